@@ -47,6 +47,15 @@ impl Application {
         // channel to send closures GUI (or any) thread -> VM thread
         let (func_gui2vm_thread_tx, mut func_gui2vm_thread_rx) = mpsc::unbounded_channel();
 
+        // closures posted to the VM thread's dispatcher (like values set with Property::sender())
+        // are processed by the same message loop
+        fui_core::Dispatcher::set_current(Some(fui_core::Dispatcher::new({
+            let func_gui2vm_thread_tx = func_gui2vm_thread_tx.clone();
+            move |f| {
+                let _ = func_gui2vm_thread_tx.send(f);
+            }
+        })));
+
         let (gui_thread_init_tx, gui_thread_init_rx) = oneshot::channel();
         let (gui_thread_exit_tx, mut gui_thread_exit_rx) = oneshot::channel();
 
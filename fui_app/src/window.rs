@@ -4,8 +4,8 @@ use anyhow::Result;
 use drawing::primitive::Primitive;
 use drawing_gl::GlContextData;
 use drawing_gl::GlRenderTarget;
-use fui_core::{Children, Grid, Rect, Size, ViewContext};
-use fui_core::{ControlObject, EventProcessor, ObservableVec};
+use fui_core::{Children, Diagnostics, Grid, Rect, Size, ViewContext};
+use fui_core::{Command, ControlObject, EventProcessor, ObservableVec, Shortcut};
 use fui_core::{ViewModel, WindowService};
 use fui_macros::ui;
 use std::cell::RefCell;
@@ -169,6 +169,23 @@ impl Window {
         window_data.view.replace(new_view);
     }
 
+    ///
+    /// Executes the command when the key combination is pressed in this window.
+    ///
+    pub fn add_shortcut(&mut self, shortcut: Shortcut, command: Command) {
+        self.data
+            .borrow_mut()
+            .event_processor
+            .add_shortcut(shortcut, command);
+    }
+
+    pub fn remove_shortcut(&mut self, shortcut: &Shortcut) {
+        self.data
+            .borrow_mut()
+            .event_processor
+            .remove_shortcut(shortcut);
+    }
+
     pub fn get_window_service(&self) -> Rc<RefCell<dyn fui_core::WindowService + 'static>> {
         let service: Rc<RefCell<dyn fui_core::WindowService + 'static>> = self.data.clone();
         service
@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 use std::marker::PhantomData;
-use std::ops::{Deref, DerefMut};
+use std::ops::Deref;
 use std::rc::Rc;
 
 use crate::ToggleButton;
@@ -53,27 +53,7 @@ where
             Rc::new(RefCell::new(elements));
 
         for radio_element in elements.borrow_mut().deref() {
-            let elements_clone = elements.clone();
-            let radio_element_clone = radio_element.clone();
-            subscriptions.push(
-                radio_element
-                    .borrow()
-                    .as_any()
-                    .downcast_ref::<R>()
-                    .unwrap()
-                    .on_checked(Box::new(move || {
-                        for el in elements_clone.borrow_mut().deref() {
-                            if !Rc::ptr_eq(&el, &radio_element_clone) {
-                                el.borrow_mut()
-                                    .deref_mut()
-                                    .as_any_mut()
-                                    .downcast_mut::<R>()
-                                    .unwrap()
-                                    .set_is_checked(false);
-                            }
-                        }
-                    })),
-            );
+            subscriptions.push(subscribe_radio_element::<R>(&radio_element, &elements));
         }
 
         let is_checked = elements.borrow_mut().into_iter().fold(false, |acc, el| {
@@ -98,51 +78,9 @@ where
         let subscriptions_clone = subscriptions.clone();
 
         let elements_clone = elements.clone();
-        elements
-            .borrow_mut()
-            .on_changed(Box::new(move |args| match args {
-                VecDiff::Clear {} => {
-                    subscriptions_clone.borrow_mut().clear();
-                }
-
-                VecDiff::InsertAt {
-                    index,
-                    value: radio_element,
-                } => {
-                    if elements_clone.borrow().len() == 0 {
-                        radio_element
-                            .borrow_mut()
-                            .as_any_mut()
-                            .downcast_mut::<R>()
-                            .unwrap()
-                            .set_is_checked(true);
-                    }
-
-                    let elements_clone = elements_clone.clone();
-                    let radio_element_clone = radio_element.clone();
-                    let subscription = radio_element
-                        .borrow()
-                        .as_any()
-                        .downcast_ref::<R>()
-                        .unwrap()
-                        .on_checked(Box::new(move || {
-                            for el in elements_clone.borrow_mut().deref() {
-                                if !Rc::ptr_eq(&el, &radio_element_clone) {
-                                    el.borrow_mut()
-                                        .as_any_mut()
-                                        .downcast_mut::<R>()
-                                        .unwrap()
-                                        .set_is_checked(false);
-                                }
-                            }
-                        }));
-                    subscriptions_clone.borrow_mut().insert(index, subscription);
-                }
-
-                VecDiff::RemoveAt { index } => {
-                    subscriptions_clone.borrow_mut().remove(index);
-                }
-            }));
+        elements.borrow_mut().on_changed(Box::new(move |args| {
+            handle_elements_change::<R>(args, &elements_clone, &subscriptions_clone)
+        }));
 
         RadioController {
             _elements: elements,
@@ -151,3 +89,88 @@ where
         }
     }
 }
+
+fn handle_elements_change<R>(
+    args: VecDiff<Rc<RefCell<dyn ControlObject>>>,
+    elements: &Rc<RefCell<dyn ObservableCollection<Rc<RefCell<dyn ControlObject>>>>>,
+    subscriptions: &Rc<RefCell<Vec<Subscription>>>,
+) where
+    R: 'static + RadioElement,
+{
+    match args {
+        VecDiff::Clear {} => {
+            subscriptions.borrow_mut().clear();
+        }
+
+        VecDiff::InsertAt {
+            index,
+            value: radio_element,
+        } => {
+            if elements.borrow().len() == 0 {
+                radio_element
+                    .borrow_mut()
+                    .as_any_mut()
+                    .downcast_mut::<R>()
+                    .unwrap()
+                    .set_is_checked(true);
+            }
+
+            let subscription = subscribe_radio_element::<R>(&radio_element, elements);
+            subscriptions.borrow_mut().insert(index, subscription);
+        }
+
+        VecDiff::RemoveAt { index } => {
+            subscriptions.borrow_mut().remove(index);
+        }
+
+        VecDiff::Replace {
+            index,
+            value: radio_element,
+        } => {
+            let subscription = subscribe_radio_element::<R>(&radio_element, elements);
+            subscriptions.borrow_mut()[index] = subscription;
+        }
+
+        VecDiff::Move {
+            old_index,
+            new_index,
+        } => {
+            let mut subscriptions = subscriptions.borrow_mut();
+            let subscription = subscriptions.remove(old_index);
+            subscriptions.insert(new_index, subscription);
+        }
+
+        VecDiff::Batch { changes } => {
+            for change in changes {
+                handle_elements_change::<R>(change, elements, subscriptions);
+            }
+        }
+    }
+}
+
+fn subscribe_radio_element<R>(
+    radio_element: &Rc<RefCell<dyn ControlObject>>,
+    elements: &Rc<RefCell<dyn ObservableCollection<Rc<RefCell<dyn ControlObject>>>>>,
+) -> Subscription
+where
+    R: 'static + RadioElement,
+{
+    let elements_clone = elements.clone();
+    let radio_element_clone = radio_element.clone();
+    radio_element
+        .borrow()
+        .as_any()
+        .downcast_ref::<R>()
+        .unwrap()
+        .on_checked(Box::new(move || {
+            for el in elements_clone.borrow_mut().deref() {
+                if !Rc::ptr_eq(&el, &radio_element_clone) {
+                    el.borrow_mut()
+                        .as_any_mut()
+                        .downcast_mut::<R>()
+                        .unwrap()
+                        .set_is_checked(false);
+                }
+            }
+        }))
+}
@@ -13,6 +13,10 @@ use crate::style::*;
 pub struct Button {
     #[builder(default = Callback::empty())]
     pub clicked: Callback<()>,
+
+    /// executed when clicked, the button is disabled when the command cannot be executed
+    #[builder(default)]
+    pub command: Option<Command>,
 }
 
 impl Button {
@@ -31,6 +35,12 @@ impl Button {
             context,
         )
     }
+
+    fn is_enabled(&self) -> bool {
+        self.command
+            .as_ref()
+            .map_or(true, |command| command.is_enabled().get())
+    }
 }
 
 //
@@ -57,10 +67,13 @@ impl DefaultButtonStyle {
 }
 
 impl Style<Button> for DefaultButtonStyle {
-    fn setup(&mut self, _data: &mut Button, control_context: &mut ControlContext) {
+    fn setup(&mut self, data: &mut Button, control_context: &mut ControlContext) {
         control_context.dirty_watch_property(&self.is_hover);
         control_context.dirty_watch_property(&self.is_pressed);
         control_context.dirty_watch_property(&self.is_focused);
+        if let Some(command) = &data.command {
+            control_context.dirty_watch_read_only_property(&command.is_enabled());
+        }
     }
 
     fn handle_event(
@@ -73,18 +86,28 @@ impl Style<Button> for DefaultButtonStyle {
     ) {
         match event {
             ControlEvent::TapDown { .. } => {
-                self.is_pressed.set(true);
+                if data.is_enabled() {
+                    self.is_pressed.set(true);
+                }
             }
 
             ControlEvent::TapUp { ref position } => {
                 if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) && data.is_enabled()
+                    {
                         data.clicked.emit(());
+                        if let Some(command) = &data.command {
+                            command.execute();
+                        }
                     }
                 }
                 self.is_pressed.set(false);
             }
 
+            ControlEvent::TapMove { .. } if !data.is_enabled() => {
+                self.is_pressed.set(false);
+            }
+
             ControlEvent::TapMove { ref position } => {
                 if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
                     if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
@@ -170,7 +193,7 @@ impl Style<Button> for DefaultButtonStyle {
 
     fn to_primitives(
         &self,
-        _data: &Button,
+        data: &Button,
         control_context: &ControlContext,
         drawing_context: &mut dyn DrawingContext,
     ) -> (Vec<Primitive>, Vec<Primitive>) {
@@ -182,6 +205,7 @@ impl Style<Button> for DefaultButtonStyle {
         let y = rect.y;
         let width = rect.width;
         let height = rect.height;
+        let is_enabled = data.is_enabled();
 
         default_theme::button(
             &mut vec,
@@ -189,8 +213,8 @@ impl Style<Button> for DefaultButtonStyle {
             y,
             width,
             height,
-            self.is_pressed.get(),
-            self.is_hover.get(),
+            self.is_pressed.get() && is_enabled,
+            self.is_hover.get() && is_enabled,
             self.is_focused.get(),
         );
 
@@ -204,6 +228,10 @@ impl Style<Button> for DefaultButtonStyle {
             overlay.append(&mut overlay2);
         }
 
+        if !is_enabled {
+            default_theme::disabled_overlay(&mut vec, x, y, width, height);
+        }
+
         (vec, overlay)
     }
 }
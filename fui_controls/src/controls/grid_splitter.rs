@@ -0,0 +1,454 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use drawing::primitive::Primitive;
+use drawing::units::{PixelPoint, PixelRect, PixelSize};
+use fui_core::*;
+use typed_builder::TypedBuilder;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GridResizeDirection {
+    /// Resizes columns when the splitter is higher than wide, rows otherwise.
+    Auto,
+
+    Columns,
+
+    Rows,
+}
+
+///
+/// Resizes the columns (or rows) of the parent Grid next to the splitter.
+///
+/// The splitter should be placed in its own column (or row) between the resized ones.
+/// The sizes are stored in the `widths` (or `heights`) property of the Grid,
+/// so they can be saved with two-way binding.
+///
+/// Example:
+///
+/// ui! {
+///     Grid {
+///         columns: 3,
+///         widths: &mut vm.widths,
+///         min_widths: vec![(0, 100.0f32), (2, 100.0f32)],
+///         FileTree { },
+///         GridSplitter { },
+///         Editor { },
+///     }
+/// }
+///
+/// where `vm.widths` is initialized with
+/// `vec![(0, Length::Exact(200.0f32)), (1, Length::Exact(5.0f32)), (2, Length::Fill(1.0f32))]`.
+///
+#[derive(TypedBuilder)]
+pub struct GridSplitter {
+    #[builder(default = GridResizeDirection::Auto)]
+    pub resize_direction: GridResizeDirection,
+}
+
+impl GridSplitter {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<RefCell<dyn ControlObject>> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultGridSplitterStyle::new(
+                    DefaultGridSplitterStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+//
+// Resizing
+//
+
+struct ResizedDefinition {
+    index: i32,
+    length: Length,
+    size: f32,
+    min_size: f32,
+    max_size: f32,
+}
+
+struct GridSplitterDrag {
+    is_columns: bool,
+    start_position: f32,
+    previous: ResizedDefinition,
+    next: ResizedDefinition,
+
+    // widths or heights of the grid
+    lengths: Property<Vec<(i32, Length)>>,
+}
+
+impl GridSplitterDrag {
+    fn begin(
+        control_context: &ControlContext,
+        is_columns: bool,
+        position: Point,
+    ) -> Option<GridSplitterDrag> {
+        let parent = control_context.get_parent()?;
+        let parent = parent.borrow();
+        let grid = &parent.as_any().downcast_ref::<StyledControl<Grid>>()?.data;
+
+        let actual_sizes = if is_columns {
+            grid.get_actual_widths()
+        } else {
+            grid.get_actual_heights()
+        };
+
+        // the column (or row) of the splitter
+        let grid_rect = parent.get_rect();
+        let rect = control_context.get_rect();
+        let (center, mut offset) = if is_columns {
+            (rect.x + rect.width / 2.0f32, grid_rect.x)
+        } else {
+            (rect.y + rect.height / 2.0f32, grid_rect.y)
+        };
+        let index = actual_sizes.iter().position(|size| {
+            offset += size;
+            center < offset
+        })? as i32;
+        if index < 1 || index + 1 >= actual_sizes.len() as i32 {
+            return None;
+        }
+
+        let get_definition = |index: i32| {
+            if is_columns {
+                ResizedDefinition {
+                    index,
+                    length: grid.get_width(index),
+                    size: actual_sizes[index as usize],
+                    min_size: grid.get_min_width(index),
+                    max_size: grid.get_max_width(index),
+                }
+            } else {
+                ResizedDefinition {
+                    index,
+                    length: grid.get_height(index),
+                    size: actual_sizes[index as usize],
+                    min_size: grid.get_min_height(index),
+                    max_size: grid.get_max_height(index),
+                }
+            }
+        };
+
+        Some(GridSplitterDrag {
+            is_columns,
+            start_position: if is_columns { position.x } else { position.y },
+            previous: get_definition(index - 1),
+            next: get_definition(index + 1),
+            lengths: if is_columns {
+                grid.widths.clone()
+            } else {
+                grid.heights.clone()
+            },
+        })
+    }
+
+    fn update(&mut self, position: Point) {
+        let position = if self.is_columns {
+            position.x
+        } else {
+            position.y
+        };
+        let (previous_length, next_length) = match Self::get_resized_lengths(
+            &self.previous,
+            &self.next,
+            position - self.start_position,
+        ) {
+            Some(lengths) => lengths,
+            None => return,
+        };
+
+        let (previous_index, next_index) = (self.previous.index, self.next.index);
+        let mut lengths = self.lengths.get();
+        lengths.retain(|(index, _)| *index != previous_index && *index != next_index);
+        lengths.push((previous_index, previous_length));
+        lengths.push((next_index, next_length));
+        lengths.sort_by_key(|(index, _)| *index);
+        self.lengths.set(lengths);
+    }
+
+    ///
+    /// Returns new lengths of the definitions moved by `delta`
+    /// or None if they cannot be resized.
+    ///
+    fn get_resized_lengths(
+        previous: &ResizedDefinition,
+        next: &ResizedDefinition,
+        delta: f32,
+    ) -> Option<(Length, Length)> {
+        // respect min and max sizes of both definitions
+        let delta = delta
+            .max(previous.min_size - previous.size)
+            .max(next.size - next.max_size)
+            .min(previous.max_size - previous.size)
+            .min(next.size - next.min_size);
+
+        let previous_size = previous.size + delta;
+        let next_size = next.size - delta;
+
+        Some(match (previous.length, next.length) {
+            (Length::Fill(previous_weight), Length::Fill(next_weight)) => {
+                // the sum of the weights is kept, so other columns are not affected
+                let total_size = previous.size + next.size;
+                if total_size <= 0.0f32 {
+                    return None;
+                }
+                let total_weight = previous_weight + next_weight;
+                (
+                    Length::Fill(total_weight * previous_size / total_size),
+                    Length::Fill(total_weight * next_size / total_size),
+                )
+            }
+            (Length::Fill(previous_weight), _) => {
+                (Length::Fill(previous_weight), Length::Exact(next_size))
+            }
+            (_, Length::Fill(next_weight)) => {
+                (Length::Exact(previous_size), Length::Fill(next_weight))
+            }
+            _ => (Length::Exact(previous_size), Length::Exact(next_size)),
+        })
+    }
+}
+
+//
+// Default GridSplitter Style
+//
+
+const DEFAULT_SIZE: f32 = 5.0f32;
+
+#[derive(TypedBuilder)]
+pub struct DefaultGridSplitterStyleParams {}
+
+pub struct DefaultGridSplitterStyle {
+    is_hover: Property<bool>,
+    is_pressed: Property<bool>,
+    drag: Option<GridSplitterDrag>,
+}
+
+impl DefaultGridSplitterStyle {
+    pub fn new(_params: DefaultGridSplitterStyleParams) -> Self {
+        DefaultGridSplitterStyle {
+            is_hover: Property::new(false),
+            is_pressed: Property::new(false),
+            drag: None,
+        }
+    }
+}
+
+impl Style<GridSplitter> for DefaultGridSplitterStyle {
+    fn setup(&mut self, _data: &mut GridSplitter, control_context: &mut ControlContext) {
+        control_context.dirty_watch_property(&self.is_hover);
+        control_context.dirty_watch_property(&self.is_pressed);
+    }
+
+    fn handle_event(
+        &mut self,
+        data: &mut GridSplitter,
+        control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        event_context: &mut dyn EventContext,
+        event: ControlEvent,
+    ) {
+        match event {
+            ControlEvent::TapDown { position } => {
+                let rect = control_context.get_rect();
+                let is_columns = match data.resize_direction {
+                    GridResizeDirection::Auto => rect.width <= rect.height,
+                    GridResizeDirection::Columns => true,
+                    GridResizeDirection::Rows => false,
+                };
+
+                self.drag = GridSplitterDrag::begin(control_context, is_columns, position);
+                if self.drag.is_some() {
+                    event_context.set_captured_control(Some(control_context.get_self_rc()));
+                    self.is_pressed.set(true);
+                }
+            }
+
+            ControlEvent::TapUp { .. } => {
+                if self.drag.take().is_some() {
+                    event_context.set_captured_control(None);
+                    self.is_pressed.set(false);
+                }
+            }
+
+            ControlEvent::TapMove { position } => {
+                if let Some(ref mut drag) = self.drag {
+                    drag.update(position);
+                }
+            }
+
+            ControlEvent::HoverChange(value) => {
+                self.is_hover.set(value);
+            }
+
+            _ => (),
+        }
+    }
+
+    fn measure(
+        &mut self,
+        _data: &mut GridSplitter,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _size: Size,
+    ) -> Size {
+        Size::new(DEFAULT_SIZE, DEFAULT_SIZE)
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut GridSplitter,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _rect: Rect,
+    ) {
+    }
+
+    fn hit_test(
+        &self,
+        _data: &GridSplitter,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        if point.is_inside(&control_context.get_rect()) {
+            Some(control_context.get_self_rc())
+        } else {
+            None
+        }
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &GridSplitter,
+        control_context: &ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        let rect = control_context.get_rect();
+
+        let color = if self.is_pressed.get() {
+            [0.0, 0.0, 0.0, 0.5]
+        } else if self.is_hover.get() {
+            [0.0, 0.0, 0.0, 0.4]
+        } else {
+            [0.0, 0.0, 0.0, 0.25]
+        };
+
+        let vec = vec![Primitive::Rectangle {
+            color,
+            rect: PixelRect::new(
+                PixelPoint::new(rect.x, rect.y),
+                PixelSize::new(rect.width, rect.height),
+            ),
+        }];
+
+        (vec, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(length: Length, size: f32, min_size: f32, max_size: f32) -> ResizedDefinition {
+        ResizedDefinition {
+            index: 0,
+            length,
+            size,
+            min_size,
+            max_size,
+        }
+    }
+
+    fn resize(
+        previous: &ResizedDefinition,
+        next: &ResizedDefinition,
+        delta: f32,
+    ) -> (Length, Length) {
+        GridSplitterDrag::get_resized_lengths(previous, next, delta).unwrap()
+    }
+
+    #[test]
+    fn exact_lengths_are_clamped_to_min_sizes() {
+        let previous = definition(Length::Exact(200.0f32), 200.0f32, 100.0f32, f32::INFINITY);
+        let next = definition(Length::Exact(300.0f32), 300.0f32, 250.0f32, f32::INFINITY);
+
+        assert_eq!(
+            resize(&previous, &next, 20.0f32),
+            (Length::Exact(220.0f32), Length::Exact(280.0f32))
+        );
+        assert_eq!(
+            resize(&previous, &next, 80.0f32),
+            (Length::Exact(250.0f32), Length::Exact(250.0f32))
+        );
+        assert_eq!(
+            resize(&previous, &next, -150.0f32),
+            (Length::Exact(100.0f32), Length::Exact(400.0f32))
+        );
+    }
+
+    #[test]
+    fn exact_lengths_are_clamped_to_max_sizes() {
+        let previous = definition(Length::Exact(200.0f32), 200.0f32, 0.0f32, 240.0f32);
+        let next = definition(Length::Exact(300.0f32), 300.0f32, 0.0f32, 330.0f32);
+
+        assert_eq!(
+            resize(&previous, &next, 100.0f32),
+            (Length::Exact(240.0f32), Length::Exact(260.0f32))
+        );
+        assert_eq!(
+            resize(&previous, &next, -100.0f32),
+            (Length::Exact(170.0f32), Length::Exact(330.0f32))
+        );
+    }
+
+    #[test]
+    fn fill_length_keeps_its_weight() {
+        let previous = definition(Length::Exact(200.0f32), 200.0f32, 0.0f32, f32::INFINITY);
+        let next = definition(Length::Fill(1.0f32), 300.0f32, 0.0f32, f32::INFINITY);
+
+        assert_eq!(
+            resize(&previous, &next, 50.0f32),
+            (Length::Exact(250.0f32), Length::Fill(1.0f32))
+        );
+        assert_eq!(
+            resize(&next, &previous, 50.0f32),
+            (Length::Fill(1.0f32), Length::Exact(150.0f32))
+        );
+    }
+
+    #[test]
+    fn fill_lengths_keep_sum_of_weights() {
+        let previous = definition(Length::Fill(1.0f32), 200.0f32, 100.0f32, f32::INFINITY);
+        let next = definition(Length::Fill(3.0f32), 200.0f32, 0.0f32, f32::INFINITY);
+
+        assert_eq!(
+            resize(&previous, &next, 100.0f32),
+            (Length::Fill(3.0f32), Length::Fill(1.0f32))
+        );
+
+        // clamped to the min size of the previous definition
+        assert_eq!(
+            resize(&previous, &next, -200.0f32),
+            (Length::Fill(1.0f32), Length::Fill(3.0f32))
+        );
+    }
+
+    #[test]
+    fn fill_lengths_of_zero_size_are_not_resized() {
+        let previous = definition(Length::Fill(1.0f32), 0.0f32, 0.0f32, f32::INFINITY);
+        let next = definition(Length::Fill(1.0f32), 0.0f32, 0.0f32, f32::INFINITY);
+
+        assert_eq!(
+            GridSplitterDrag::get_resized_lengths(&previous, &next, 10.0f32),
+            None
+        );
+    }
+}
@@ -6,6 +6,7 @@ use typed_builder::TypedBuilder;
 use typemap::TypeMap;
 
 use crate::controls::*;
+use crate::style::default_theme;
 use crate::{DataHolder, GestureArea};
 use fui_core::*;
 
@@ -106,9 +107,10 @@ impl Menu {
 
             MenuItem::Text {
                 text,
-                shortcut: _shortcut,
+                shortcut,
                 icon: _icon,
                 callback,
+                command,
                 sub_items,
             } => {
                 let has_sub_items = sub_items.len() > 0;
@@ -117,6 +119,27 @@ impl Menu {
                 let background_property = Property::new([0.0f32, 0.0f32, 0.0f32, 0.0f32]);
                 let foreground_property = Property::new([0.0f32, 0.0f32, 0.0f32, 1.0f32]);
 
+                // items with disabled command are grayed out and ignore taps
+                let is_enabled_property = match &command {
+                    Some(command) => {
+                        let mut is_enabled_property = Property::new(command.is_enabled().get());
+                        is_enabled_property.bind_read_only(&command.is_enabled());
+                        is_enabled_property
+                    }
+                    None => Property::new(true),
+                };
+                let text_color_property = Property::computed2(
+                    &foreground_property,
+                    &is_enabled_property,
+                    |color, is_enabled| {
+                        if is_enabled {
+                            color
+                        } else {
+                            default_theme::DISABLED_COLOR
+                        }
+                    },
+                );
+
                 let mut on_hover_callback = Callback::empty();
                 let mut on_tap_up_callback = Callback::empty();
 
@@ -126,13 +149,17 @@ impl Menu {
                     // open sub menu on tap down
                     let mut is_menu_active_prop_clone = is_menu_active_prop.clone();
                     let mut is_open_prop_clone = is_open_prop.clone();
+                    let is_enabled_property_clone = is_enabled_property.clone();
                     on_tap_up_callback.set_sync(move |_| {
                         if has_sub_items {
                             is_menu_active_prop_clone.set(true);
                             is_open_prop_clone.set(true);
-                        } else {
+                        } else if is_enabled_property_clone.get() {
                             // execute menu command
                             callback.emit(());
+                            if let Some(command) = &command {
+                                command.execute();
+                            }
                         }
                     });
 
@@ -197,12 +224,16 @@ impl Menu {
                     });
 
                     let mut is_menu_active_prop_clone = is_menu_active_prop.clone();
+                    let is_enabled_property_clone = is_enabled_property.clone();
                     on_tap_up_callback.set_sync(move |_| {
-                        if !has_sub_items {
+                        if !has_sub_items && is_enabled_property_clone.get() {
                             // close menu
                             is_menu_active_prop_clone.set(false);
                             // execute menu command
                             callback.emit(());
+                            if let Some(command) = &command {
+                                command.execute();
+                            }
                         }
                     });
                 }
@@ -213,11 +244,18 @@ impl Menu {
                         Column: 1,
                         Margin: Thickness::new(5.0f32, 0.0f32, 5.0f32, 0.0f32),
                         Style: Dynamic {
-                            color: foreground_property.clone()
+                            color: &text_color_property
                         },
                         text: text
                     })
                 } else {
+                    // no space for items without the shortcut
+                    let shortcut_margin = if shortcut.is_some() {
+                        Thickness::new(10.0f32, 0.0f32, 0.0f32, 0.0f32)
+                    } else {
+                        Thickness::all(0.0f32)
+                    };
+
                     ui!(
                         Grid {
                             columns: 4,
@@ -231,14 +269,21 @@ impl Menu {
                             Text {
                                 Row: 0, Column: 1,
                                 HorizontalAlignment: Alignment::Start,
-                                Style: Dynamic { color: foreground_property.clone() },
+                                Style: Dynamic { color: &text_color_property },
 
                                 text: text
                             },
 
+                            Text {
+                                Row: 0, Column: 2,
+                                Margin: shortcut_margin,
+                                Style: Dynamic { color: &text_color_property },
+                                text: shortcut.unwrap_or_default(),
+                            },
+
                             Text {
                                 Row: 0, Column: 3,
-                                Style: Dynamic { color: foreground_property.clone() },
+                                Style: Dynamic { color: &text_color_property },
                                 text: if sub_items.len() > 0 { ">" } else { "" },
                             }
                         }
@@ -1,4 +1,4 @@
-use fui_core::{Callback, ControlObject};
+use fui_core::{Callback, Command, ControlObject, Shortcut};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -9,6 +9,7 @@ pub enum MenuItem {
         shortcut: Option<String>,
         icon: Option<Rc<RefCell<dyn ControlObject>>>,
         callback: Callback<()>,
+        command: Option<Command>,
         sub_items: Vec<MenuItem>,
     },
     Custom {
@@ -25,6 +26,7 @@ impl MenuItem {
             shortcut: None,
             icon: None,
             callback: Callback::empty(),
+            command: None,
             sub_items,
         }
     }
@@ -35,6 +37,7 @@ impl MenuItem {
             shortcut: None,
             icon: None,
             callback,
+            command: None,
             sub_items: Vec::new(),
         }
     }
@@ -50,6 +53,24 @@ impl MenuItem {
             shortcut,
             icon,
             callback,
+            command: None,
+            sub_items: Vec::new(),
+        }
+    }
+
+    ///
+    /// Menu item executing the command. The item is disabled when the command cannot be executed.
+    ///
+    /// The shortcut is only shown next to the text,
+    /// it has to be registered separately with `Window::add_shortcut()`.
+    ///
+    pub fn command(text: &str, shortcut: Option<Shortcut>, command: Command) -> Self {
+        MenuItem::Text {
+            text: text.into(),
+            shortcut: shortcut.map(|shortcut| shortcut.to_string()),
+            icon: None,
+            callback: Callback::empty(),
+            command: Some(command),
             sub_items: Vec::new(),
         }
     }
@@ -3,6 +3,7 @@ pub use border::*;
 pub use busy_indicator::*;
 pub use button::*;
 pub use drop_down::*;
+pub use grid_splitter::*;
 pub use menu::*;
 pub use menu_item::*;
 pub use popup::*;
@@ -21,6 +22,7 @@ mod border;
 mod busy_indicator;
 mod button;
 mod drop_down;
+mod grid_splitter;
 mod menu;
 mod menu_item;
 mod popup;
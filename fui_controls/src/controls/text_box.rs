@@ -52,6 +52,9 @@ pub struct DefaultTextBoxStyleParams {
 pub struct DefaultTextBoxStyle {
     params: DefaultTextBoxStyleParams,
 
+    // validation errors of the text (if it is validated)
+    errors: Option<ReadOnlyProperty<Vec<String>>>,
+
     is_hover: bool,
     is_focused: bool,
 
@@ -65,6 +68,8 @@ impl DefaultTextBoxStyle {
         DefaultTextBoxStyle {
             params,
 
+            errors: None,
+
             is_hover: false,
             is_focused: false,
 
@@ -198,6 +203,10 @@ impl DefaultTextBoxStyle {
 impl Style<TextBox> for DefaultTextBoxStyle {
     fn setup(&mut self, data: &mut TextBox, control_context: &mut ControlContext) {
         control_context.dirty_watch_property(&data.text);
+        self.errors = data.text.existing_errors();
+        if let Some(errors) = &self.errors {
+            control_context.dirty_watch_read_only_property(errors);
+        }
     }
 
     fn handle_event(
@@ -435,6 +444,15 @@ impl Style<TextBox> for DefaultTextBoxStyle {
             },
         );
 
+        let errors = self
+            .errors
+            .as_ref()
+            .map(|errors| errors.get())
+            .unwrap_or_default();
+        if !errors.is_empty() {
+            default_theme::border_invalid(&mut vec, x, y, width, height);
+        }
+
         let mut vec2 = Vec::new();
 
         vec2.push(Primitive::Text {
@@ -474,6 +492,27 @@ impl Style<TextBox> for DefaultTextBoxStyle {
 
         vec.append(&mut vec2);
 
-        (vec, Vec::new())
+        // show validation error under the text box
+        let mut overlay = Vec::new();
+        if let Some(error) = errors.first() {
+            let error_font_size = self.params.font_size * 3 / 4;
+            let (error_width, error_height) = drawing_context
+                .get_resources()
+                .get_font_dimensions(self.params.font_name, error_font_size, error)
+                .unwrap_or((0, 0));
+            overlay.push(Primitive::Text {
+                resource_key: self.params.font_name.to_string(),
+                color: default_theme::INVALID_COLOR,
+                position: PixelPoint::new(x + 4.0f32, y + height + 2.0f32),
+                clipping_rect: PixelRect::new(
+                    PixelPoint::new(x + 4.0f32, y + height + 2.0f32),
+                    PixelSize::new(error_width as f32, error_height as f32),
+                ),
+                size: Length::new(error_font_size as f32),
+                text: error.clone(),
+            });
+        }
+
+        (vec, overlay)
     }
 }
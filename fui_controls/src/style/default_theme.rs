@@ -14,6 +14,9 @@ const GRADIENT_BOT_NORMAL: Color = [0.28, 0.28, 0.28, 1.0];
 const HOVER_HIGHLIGHT: [f32; 3] = [1.25f32, 1.25f32, 1.25f32];
 const PRESSED_HIGHLIGHT: [f32; 3] = [0.75f32, 0.75f32, 0.75f32];
 const FOCUSED_HIGHLIGHT: [f32; 3] = [2.0f32, 2.0f32, 1.0f32];
+pub const INVALID_COLOR: Color = [0.9, 0.1, 0.1, 1.0];
+pub const DISABLED_COLOR: Color = [0.5, 0.5, 0.5, 1.0];
+const DISABLED_OVERLAY_COLOR: Color = [0.2, 0.2, 0.2, 0.6];
 
 fn multiply_color(color: Color, factor: [f32; 3]) -> Color {
     [
@@ -349,6 +352,29 @@ pub fn border_3d_edit(
     });
 }
 
+pub fn border_invalid(vec: &mut Vec<Primitive>, x: f32, y: f32, width: f32, height: f32) {
+    vec.push(Primitive::Stroke {
+        path: pixel_rect_path(
+            PixelRect::new(
+                PixelPoint::new(x + 1.0f32, y + 1.0f32),
+                PixelSize::new(width - 2.0f32, height - 2.0f32),
+            ),
+            PixelThickness::new(2.0f32),
+        ),
+        thickness: PixelThickness::new(2.0f32),
+        brush: Brush::Color {
+            color: INVALID_COLOR,
+        },
+    });
+}
+
+pub fn disabled_overlay(vec: &mut Vec<Primitive>, x: f32, y: f32, width: f32, height: f32) {
+    vec.push(Primitive::Rectangle {
+        color: DISABLED_OVERLAY_COLOR,
+        rect: PixelRect::new(PixelPoint::new(x, y), PixelSize::new(width, height)),
+    });
+}
+
 pub fn gradient_rect(
     vec: &mut Vec<Primitive>,
     x: f32,
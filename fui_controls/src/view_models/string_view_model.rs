@@ -1,5 +1,5 @@
 use crate::Text;
-use fui_core::{Children, ControlObject, ViewContext, ViewModel};
+use fui_core::{Children, ControlObject, Diagnostics, ViewContext, ViewModel};
 use fui_macros::ui;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -1,10 +1,12 @@
 use std::cell::RefCell;
+use std::hash::Hash;
 use std::rc::Rc;
 
 use crate::control::ControlObject;
 use crate::observable::ObservableVec;
 use crate::{
-    observable::ObservableCollectionExt, ObservableCollectionFlatMap, ObservableCollectionMap,
+    observable::ObservableCollectionExt, CollectionView, ObservableCollectionFlatMap,
+    ObservableCollectionMap, ObservableMapValues,
 };
 use crate::{view::ViewModel, ObservableCollection, Property};
 
@@ -108,6 +110,53 @@ impl From<&ObservableCollectionFlatMap<Rc<RefCell<dyn ControlObject>>>>
     }
 }
 
+///
+/// Converts CollectionView to observable collection.
+///
+
+impl<V> From<&CollectionView<Rc<RefCell<V>>>>
+    for Box<dyn ObservableCollection<Rc<RefCell<dyn ControlObject>>>>
+where
+    V: 'static + ViewModel,
+{
+    fn from(src: &CollectionView<Rc<RefCell<V>>>) -> Self {
+        Box::new(src.map(|vm| ViewModel::create_view(vm)))
+    }
+}
+
+impl From<&CollectionView<Rc<RefCell<dyn ControlObject>>>>
+    for Box<dyn ObservableCollection<Rc<RefCell<dyn ControlObject>>>>
+{
+    fn from(src: &CollectionView<Rc<RefCell<dyn ControlObject>>>) -> Self {
+        Box::new(src.map(|el| el.clone()))
+    }
+}
+
+///
+/// Converts values of ObservableMap to observable collection.
+///
+
+impl<K, V> From<&ObservableMapValues<K, Rc<RefCell<V>>>>
+    for Box<dyn ObservableCollection<Rc<RefCell<dyn ControlObject>>>>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + ViewModel,
+{
+    fn from(src: &ObservableMapValues<K, Rc<RefCell<V>>>) -> Self {
+        Box::new(src.map(|vm| ViewModel::create_view(vm)))
+    }
+}
+
+impl<K> From<&ObservableMapValues<K, Rc<RefCell<dyn ControlObject>>>>
+    for Box<dyn ObservableCollection<Rc<RefCell<dyn ControlObject>>>>
+where
+    K: 'static + Clone + Eq + Hash,
+{
+    fn from(src: &ObservableMapValues<K, Rc<RefCell<dyn ControlObject>>>) -> Self {
+        Box::new(src.map(|el| el.clone()))
+    }
+}
+
 ///
 /// Converts Property to observable collection.
 ///
@@ -1,4 +1,4 @@
-use crate::{Rect, Size};
+use crate::{Property, Rect, Size};
 use typemap::TypeMap;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -18,13 +18,13 @@ impl Alignment {
         default_vertical_alignment: Alignment,
     ) -> Rect {
         let horizontal = if let Some(h) = map.get::<HorizontalAlignment>() {
-            *h
+            h.get()
         } else {
             default_horizontal_alignment
         };
 
         let vertical = if let Some(v) = map.get::<VerticalAlignment>() {
-            *v
+            v.get()
         } else {
             default_vertical_alignment
         };
@@ -59,10 +59,10 @@ impl Alignment {
 
 pub struct HorizontalAlignment;
 impl typemap::Key for HorizontalAlignment {
-    type Value = Alignment;
+    type Value = Property<Alignment>;
 }
 
 pub struct VerticalAlignment;
 impl typemap::Key for VerticalAlignment {
-    type Value = Alignment;
+    type Value = Property<Alignment>;
 }
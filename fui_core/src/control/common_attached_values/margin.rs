@@ -1,4 +1,4 @@
-use crate::{Rect, Size, Thickness};
+use crate::{Property, Rect, Size, Thickness};
 use typemap::TypeMap;
 
 //
@@ -7,13 +7,13 @@ use typemap::TypeMap;
 
 pub struct Margin;
 impl typemap::Key for Margin {
-    type Value = Thickness;
+    type Value = Property<Thickness>;
 }
 
 impl Margin {
     pub fn add_to_rect(mut rect: Rect, map: &TypeMap) -> Rect {
         let thickness = if let Some(t) = map.get::<Margin>() {
-            *t
+            t.get()
         } else {
             return rect;
         };
@@ -32,7 +32,7 @@ impl Margin {
 
     pub fn add_to_size(mut size: Size, map: &TypeMap) -> Size {
         let thickness = if let Some(t) = map.get::<Margin>() {
-            *t
+            t.get()
         } else {
             return size;
         };
@@ -49,7 +49,7 @@ impl Margin {
 
     pub fn remove_from_size(mut size: Size, map: &TypeMap) -> Size {
         let thickness = if let Some(t) = map.get::<Margin>() {
-            *t
+            t.get()
         } else {
             return size;
         };
@@ -66,7 +66,7 @@ impl Margin {
 
     pub fn remove_from_rect(mut rect: Rect, map: &TypeMap) -> Rect {
         let thickness = if let Some(t) = map.get::<Margin>() {
-            *t
+            t.get()
         } else {
             return rect;
         };
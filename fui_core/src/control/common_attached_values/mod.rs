@@ -4,5 +4,8 @@ pub use alignment::*;
 mod margin;
 pub use margin::*;
 
+mod size_constraints;
+pub use size_constraints::*;
+
 mod visible;
 pub use visible::*;
@@ -0,0 +1,247 @@
+use crate::{Alignment, HorizontalAlignment, Property, Rect, Size, VerticalAlignment};
+use typemap::TypeMap;
+
+//
+// Attached values
+//
+
+/// Width of the control (without the margin).
+pub struct Width;
+impl typemap::Key for Width {
+    type Value = Property<f32>;
+}
+
+/// Height of the control (without the margin).
+pub struct Height;
+impl typemap::Key for Height {
+    type Value = Property<f32>;
+}
+
+pub struct MinWidth;
+impl typemap::Key for MinWidth {
+    type Value = Property<f32>;
+}
+
+pub struct MinHeight;
+impl typemap::Key for MinHeight {
+    type Value = Property<f32>;
+}
+
+pub struct MaxWidth;
+impl typemap::Key for MaxWidth {
+    type Value = Property<f32>;
+}
+
+pub struct MaxHeight;
+impl typemap::Key for MaxHeight {
+    type Value = Property<f32>;
+}
+
+///
+/// Size limits of the control from the size attached values.
+///
+/// `Width` and `Height` override the size of the content, but they are still
+/// limited by the min and max values. The min values win over the max values.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SizeConstraints {
+    pub min_width: f32,
+    pub max_width: f32,
+    pub min_height: f32,
+    pub max_height: f32,
+}
+
+impl SizeConstraints {
+    pub fn new(map: &TypeMap) -> Self {
+        let (min_width, max_width) = Self::get_limits::<Width, MinWidth, MaxWidth>(map);
+        let (min_height, max_height) = Self::get_limits::<Height, MinHeight, MaxHeight>(map);
+        SizeConstraints {
+            min_width,
+            max_width,
+            min_height,
+            max_height,
+        }
+    }
+
+    pub fn limit_size(&self, size: Size) -> Size {
+        Size::new(
+            size.width.min(self.max_width).max(self.min_width),
+            size.height.min(self.max_height).max(self.min_height),
+        )
+    }
+
+    ///
+    /// Limits the rect to the min and max size.
+    ///
+    /// The limited rect is placed according to the alignment
+    /// (stretched controls are centered).
+    ///
+    pub fn limit_rect(&self, mut rect: Rect, map: &TypeMap) -> Rect {
+        let width = rect.width.min(self.max_width).max(self.min_width);
+        if rect.width != width {
+            let free_space = rect.width - width;
+            rect.x += match map.get::<HorizontalAlignment>().map(|h| h.get()) {
+                Some(Alignment::Start) => 0.0f32,
+                Some(Alignment::End) => free_space,
+                _ => free_space / 2.0f32,
+            };
+            rect.width = width;
+        }
+
+        let height = rect.height.min(self.max_height).max(self.min_height);
+        if rect.height != height {
+            let free_space = rect.height - height;
+            rect.y += match map.get::<VerticalAlignment>().map(|v| v.get()) {
+                Some(Alignment::Start) => 0.0f32,
+                Some(Alignment::End) => free_space,
+                _ => free_space / 2.0f32,
+            };
+            rect.height = height;
+        }
+
+        rect
+    }
+
+    fn get_limits<S, Min, Max>(map: &TypeMap) -> (f32, f32)
+    where
+        S: typemap::Key<Value = Property<f32>>,
+        Min: typemap::Key<Value = Property<f32>>,
+        Max: typemap::Key<Value = Property<f32>>,
+    {
+        let min = map.get::<Min>().map(|min| min.get()).unwrap_or(0.0f32);
+        let max = map
+            .get::<Max>()
+            .map(|max| max.get())
+            .unwrap_or(f32::INFINITY)
+            .max(min);
+
+        match map.get::<S>() {
+            Some(size) => {
+                let size = size.get().min(max).max(min);
+                (size, size)
+            }
+            None => (min, max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_constraints(values: &[(&str, f32)]) -> (SizeConstraints, TypeMap) {
+        let mut map = TypeMap::new();
+        for (name, value) in values {
+            let value = Property::new(*value);
+            match *name {
+                "Width" => map.insert::<Width>(value),
+                "Height" => map.insert::<Height>(value),
+                "MinWidth" => map.insert::<MinWidth>(value),
+                "MinHeight" => map.insert::<MinHeight>(value),
+                "MaxWidth" => map.insert::<MaxWidth>(value),
+                "MaxHeight" => map.insert::<MaxHeight>(value),
+                _ => unreachable!(),
+            };
+        }
+        (SizeConstraints::new(&map), map)
+    }
+
+    #[test]
+    fn no_limits() {
+        let (constraints, map) = new_constraints(&[]);
+        assert_eq!(
+            constraints.limit_size(Size::new(30.0f32, 40.0f32)),
+            Size::new(30.0f32, 40.0f32)
+        );
+        let rect = Rect::new(1.0f32, 2.0f32, 30.0f32, 40.0f32);
+        assert_eq!(constraints.limit_rect(rect, &map), rect);
+    }
+
+    #[test]
+    fn size_inside_min_max() {
+        let (constraints, _) = new_constraints(&[
+            ("Width", 50.0f32),
+            ("MinWidth", 20.0f32),
+            ("MaxWidth", 100.0f32),
+        ]);
+        assert_eq!(
+            constraints.limit_size(Size::new(10.0f32, 10.0f32)),
+            Size::new(50.0f32, 10.0f32)
+        );
+        assert_eq!(
+            constraints.limit_size(Size::new(200.0f32, 10.0f32)),
+            Size::new(50.0f32, 10.0f32)
+        );
+    }
+
+    #[test]
+    fn size_outside_min_max() {
+        let (constraints, _) = new_constraints(&[("Height", 500.0f32), ("MaxHeight", 100.0f32)]);
+        assert_eq!(
+            constraints.limit_size(Size::new(10.0f32, 10.0f32)),
+            Size::new(10.0f32, 100.0f32)
+        );
+
+        let (constraints, _) = new_constraints(&[("Height", 5.0f32), ("MinHeight", 20.0f32)]);
+        assert_eq!(
+            constraints.limit_size(Size::new(10.0f32, 10.0f32)),
+            Size::new(10.0f32, 20.0f32)
+        );
+    }
+
+    #[test]
+    fn min_wins_over_max() {
+        let (constraints, _) = new_constraints(&[("MinWidth", 80.0f32), ("MaxWidth", 50.0f32)]);
+        assert_eq!(constraints.min_width, 80.0f32);
+        assert_eq!(constraints.max_width, 80.0f32);
+        assert_eq!(
+            constraints.limit_size(Size::new(10.0f32, 10.0f32)),
+            Size::new(80.0f32, 10.0f32)
+        );
+    }
+
+    #[test]
+    fn max_with_alignment() {
+        let rect = Rect::new(0.0f32, 0.0f32, 100.0f32, 100.0f32);
+        let (constraints, mut map) =
+            new_constraints(&[("MaxWidth", 40.0f32), ("MaxHeight", 60.0f32)]);
+
+        assert_eq!(
+            constraints.limit_rect(rect, &map),
+            Rect::new(30.0f32, 20.0f32, 40.0f32, 60.0f32)
+        );
+
+        map.insert::<HorizontalAlignment>(Property::new(Alignment::Start));
+        map.insert::<VerticalAlignment>(Property::new(Alignment::End));
+        assert_eq!(
+            constraints.limit_rect(rect, &map),
+            Rect::new(0.0f32, 40.0f32, 40.0f32, 60.0f32)
+        );
+
+        map.insert::<HorizontalAlignment>(Property::new(Alignment::End));
+        map.insert::<VerticalAlignment>(Property::new(Alignment::Stretch));
+        assert_eq!(
+            constraints.limit_rect(rect, &map),
+            Rect::new(60.0f32, 20.0f32, 40.0f32, 60.0f32)
+        );
+    }
+
+    #[test]
+    fn min_with_alignment() {
+        let rect = Rect::new(100.0f32, 100.0f32, 20.0f32, 20.0f32);
+        let (constraints, mut map) =
+            new_constraints(&[("MinWidth", 40.0f32), ("MinHeight", 60.0f32)]);
+
+        assert_eq!(
+            constraints.limit_rect(rect, &map),
+            Rect::new(90.0f32, 80.0f32, 40.0f32, 60.0f32)
+        );
+
+        map.insert::<HorizontalAlignment>(Property::new(Alignment::Start));
+        map.insert::<VerticalAlignment>(Property::new(Alignment::End));
+        assert_eq!(
+            constraints.limit_rect(rect, &map),
+            Rect::new(100.0f32, 60.0f32, 40.0f32, 60.0f32)
+        );
+    }
+}
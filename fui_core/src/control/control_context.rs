@@ -1,10 +1,10 @@
 use crate::view::ViewContext;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 use typemap::TypeMap;
 
 use crate::control::*;
-use crate::{observable::*, spawn_local_and_forget, Children, Rect, Services};
+use crate::{observable::*, spawn_local_and_forget, Children, Column, Rect, Row, Services};
 
 pub struct ControlContext {
     self_weak: Option<Weak<RefCell<dyn ControlObject>>>,
@@ -21,6 +21,12 @@ pub struct ControlContext {
     rect: Rect,
 
     is_dirty: bool,
+
+    // set by the dirty watch handlers when the control is borrowed
+    is_dirty_pending: Rc<Cell<bool>>,
+
+    // the last field, so it is dropped after all the other resources of the control
+    diagnostics_owner: Option<DiagnosticsOwner>,
 }
 
 impl ControlContext {
@@ -35,6 +41,8 @@ impl ControlContext {
             services: None,
             rect: Rect::empty(),
             is_dirty: true,
+            is_dirty_pending: Rc::new(Cell::new(false)),
+            diagnostics_owner: None,
         }
     }
 
@@ -96,6 +104,10 @@ impl ControlContext {
         self.services = services;
     }
 
+    pub(crate) fn set_diagnostics_owner(&mut self, owner: DiagnosticsOwner) {
+        self.diagnostics_owner = Some(owner);
+    }
+
     pub fn get_rect(&self) -> Rect {
         self.rect
     }
@@ -135,28 +147,90 @@ impl ControlContext {
         }
     }
 
+    ///
+    /// Marks the control dirty if it was marked by the dirty watch handler
+    /// while it was borrowed.
+    ///
+    pub(crate) fn apply_pending_dirty(&mut self) {
+        if self.is_dirty_pending.replace(false) {
+            self.set_is_dirty(true);
+        }
+    }
+
+    #[track_caller]
     pub fn dirty_watch_property<T>(&mut self, property: &Property<T>)
     where
         T: 'static + Clone + PartialEq,
     {
-        let self_weak = self.self_weak.clone().unwrap();
+        let mark_dirty = self.get_mark_dirty();
+        self.dirty_event_subscriptions
+            .push(property.on_changed(move |_| mark_dirty()));
+    }
+
+    pub fn dirty_watch_read_only_property<T>(&mut self, property: &ReadOnlyProperty<T>)
+    where
+        T: 'static + Clone + PartialEq,
+    {
+        let mark_dirty = self.get_mark_dirty();
         self.dirty_event_subscriptions
-            .push(property.on_changed(move |_| {
-                self_weak.upgrade().map(|control| {
-                    control.borrow_mut().get_context_mut().set_is_dirty(true);
-                });
-            }));
+            .push(property.on_changed(move |_| mark_dirty()));
     }
 
     pub fn dirty_watch_attached_properties(&mut self) {
-        if let Some(visible) = self.attached_values.get::<Visible>() {
-            let self_weak = self.self_weak.clone().unwrap();
+        self.dirty_watch_attached_property::<Visible, _>();
+        self.dirty_watch_attached_property::<Margin, _>();
+        self.dirty_watch_attached_property::<HorizontalAlignment, _>();
+        self.dirty_watch_attached_property::<VerticalAlignment, _>();
+        self.dirty_watch_attached_property::<Width, _>();
+        self.dirty_watch_attached_property::<Height, _>();
+        self.dirty_watch_attached_property::<MinWidth, _>();
+        self.dirty_watch_attached_property::<MinHeight, _>();
+        self.dirty_watch_attached_property::<MaxWidth, _>();
+        self.dirty_watch_attached_property::<MaxHeight, _>();
+        self.dirty_watch_attached_property::<Row, _>();
+        self.dirty_watch_attached_property::<Column, _>();
+    }
+
+    ///
+    /// Marks the control dirty when the attached property changes.
+    ///
+    /// Used by layouts for their own attached properties.
+    ///
+    pub fn dirty_watch_attached_property<K, T>(&mut self)
+    where
+        K: typemap::Key<Value = Property<T>>,
+        T: 'static + Clone + PartialEq,
+    {
+        if let Some(property) = self.attached_values.get::<K>() {
+            let mark_dirty = self.get_mark_dirty();
             self.dirty_event_subscriptions
-                .push(visible.on_changed(move |_| {
-                    self_weak.upgrade().map(|control| {
-                        control.borrow_mut().get_context_mut().set_is_dirty(true);
-                    });
-                }));
+                .push(property.on_changed(move |_| mark_dirty()));
+        }
+    }
+
+    fn get_mark_dirty(&self) -> impl 'static + Fn() {
+        let self_weak = self.self_weak.clone().unwrap();
+        let is_dirty_pending = self.is_dirty_pending.clone();
+        move || mark_dirty(&self_weak, &is_dirty_pending)
+    }
+}
+
+fn mark_dirty(control: &Weak<RefCell<dyn ControlObject>>, is_dirty_pending: &Rc<Cell<bool>>) {
+    if let Some(control_rc) = control.upgrade() {
+        if let Ok(mut control) = control_rc.try_borrow_mut() {
+            control.get_context_mut().set_is_dirty(true);
+        } else if !is_dirty_pending.replace(true) {
+            // the control is borrowed when the handler is called synchronously
+            // (from the control's own methods in the synchronous mode),
+            // so it is marked when its method returns (or later by the task)
+            let control = control.clone();
+            spawn_local_and_forget(async move {
+                if let Some(control) = control.upgrade() {
+                    if let Ok(mut control) = control.try_borrow_mut() {
+                        control.get_context_mut().apply_pending_dirty();
+                    }
+                }
+            });
         }
     }
 }
@@ -19,6 +19,10 @@ pub struct StyledControl<D> {
 
 impl<D: 'static> StyledControl<D> {
     pub fn new(data: D, style: Box<dyn Style<D>>, view_context: ViewContext) -> Rc<RefCell<Self>> {
+        // resources created during the setup are owned by the control
+        let diagnostics_owner = DiagnosticsOwner::new::<D>();
+        let diagnostics_scope = diagnostics_owner.enter();
+
         let control = Rc::new(RefCell::new(StyledControl {
             data,
             style,
@@ -32,20 +36,7 @@ impl<D: 'static> StyledControl<D> {
         let control_clone: Rc<RefCell<dyn ControlObject>> = control.clone();
         let handler = Box::new(
             move |changed_args: VecDiff<Rc<RefCell<dyn ControlObject>>>| {
-                if let VecDiff::InsertAt {
-                    index: _,
-                    value: child,
-                } = changed_args
-                {
-                    child
-                        .borrow_mut()
-                        .get_context_mut()
-                        .set_parent(&control_clone);
-
-                    // dynamically created controls require to set services
-                    let services = control_clone.borrow_mut().get_context().get_services();
-                    child.borrow_mut().get_context_mut().set_services(services);
-                }
+                setup_new_children(&control_clone, changed_args);
                 control_clone
                     .borrow_mut()
                     .get_context_mut()
@@ -76,6 +67,12 @@ impl<D: 'static> StyledControl<D> {
 
         control.borrow_mut().setup();
 
+        drop(diagnostics_scope);
+        control
+            .borrow_mut()
+            .control_context
+            .set_diagnostics_owner(diagnostics_owner);
+
         control
     }
 
@@ -88,6 +85,41 @@ impl<D: 'static> StyledControl<D> {
     }
 }
 
+///
+/// Sets parent and services of the children added dynamically.
+///
+/// Moved children are left untouched.
+///
+fn setup_new_children(
+    control: &Rc<RefCell<dyn ControlObject>>,
+    changed_args: VecDiff<Rc<RefCell<dyn ControlObject>>>,
+) {
+    match changed_args {
+        VecDiff::InsertAt {
+            index: _,
+            value: child,
+        }
+        | VecDiff::Replace {
+            index: _,
+            value: child,
+        } => {
+            child.borrow_mut().get_context_mut().set_parent(control);
+
+            // dynamically created controls require to set services
+            let services = control.borrow_mut().get_context().get_services();
+            child.borrow_mut().get_context_mut().set_services(services);
+        }
+
+        VecDiff::Batch { changes } => {
+            for change in changes {
+                setup_new_children(control, change);
+            }
+        }
+
+        VecDiff::Clear {} | VecDiff::RemoveAt { .. } | VecDiff::Move { .. } => (),
+    }
+}
+
 pub trait ControlExtensions<D> {
     fn with_vm<V: 'static, F: 'static + Fn(&Rc<RefCell<V>>, &mut StyledControl<D>)>(
         self,
@@ -160,6 +192,7 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
     fn setup(&mut self) {
         self.control_context.dirty_watch_attached_properties();
         self.style.setup(&mut self.data, &mut self.control_context);
+        self.control_context.apply_pending_dirty();
     }
 
     fn handle_event(
@@ -174,7 +207,8 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
             drawing_context,
             event_context,
             event,
-        )
+        );
+        self.control_context.apply_pending_dirty();
     }
 
     fn measure(&mut self, drawing_context: &mut dyn DrawingContext, mut size: Size) {
@@ -188,6 +222,9 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
 
         size = Margin::remove_from_size(size, &self.control_context.get_attached_values());
 
+        let size_constraints = SizeConstraints::new(self.control_context.get_attached_values());
+        size = size_constraints.limit_size(size);
+
         let mut measured_size = self.style.measure(
             &mut self.data,
             &mut self.control_context,
@@ -195,6 +232,7 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
             size,
         );
 
+        measured_size = size_constraints.limit_size(measured_size);
         measured_size =
             Margin::add_to_size(measured_size, &self.control_context.get_attached_values());
 
@@ -226,6 +264,7 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
             Alignment::Stretch,
         );
         new_rect = Margin::remove_from_rect(new_rect, &map);
+        new_rect = SizeConstraints::new(map).limit_rect(new_rect, map);
 
         self.control_context.set_rect(new_rect);
         self.style.set_rect(
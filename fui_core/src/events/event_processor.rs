@@ -5,7 +5,7 @@ use std::{
 };
 
 use crate::control::*;
-use crate::{events::*, DrawingContext, Point};
+use crate::{events::*, Command, DrawingContext, Point};
 
 struct QueuedEvent {
     pub control: Rc<RefCell<dyn ControlObject>>,
@@ -22,6 +22,8 @@ pub struct EventProcessor {
     gesture_detector: GestureDetector,
 
     event_queue: VecDeque<QueuedEvent>,
+
+    shortcuts: Vec<(Shortcut, Command)>,
 }
 
 impl EventProcessor {
@@ -36,9 +38,24 @@ impl EventProcessor {
             gesture_detector: GestureDetector::new(),
 
             event_queue: VecDeque::new(),
+
+            shortcuts: Vec::new(),
         }
     }
 
+    ///
+    /// Registers command executed when the key combination is pressed.
+    ///
+    /// Shortcuts are handled before the key is sent to the focused control.
+    ///
+    pub fn add_shortcut(&mut self, shortcut: Shortcut, command: Command) {
+        self.shortcuts.push((shortcut, command));
+    }
+
+    pub fn remove_shortcut(&mut self, shortcut: &Shortcut) {
+        self.shortcuts.retain(|(s, _)| s != shortcut);
+    }
+
     pub fn handle_event(
         &mut self,
         root_view: &Rc<RefCell<dyn ControlObject>>,
@@ -66,6 +83,18 @@ impl EventProcessor {
     ) {
         match event {
             InputEvent::KeyboardInput(key_event) => {
+                let command = self
+                    .shortcuts
+                    .iter()
+                    .find(|(shortcut, _)| shortcut.matches(key_event))
+                    .map(|(_, command)| command);
+                // the key of the disabled command goes to the focused control
+                if let Some(command) = command {
+                    if command.execute() {
+                        return;
+                    }
+                }
+
                 self.queue_event(
                     self.get_focused_control(),
                     ControlEvent::KeyboardInput(key_event.clone()),
@@ -15,3 +15,6 @@ pub use self::input_event::*;
 
 mod key_event;
 pub use self::key_event::*;
+
+mod shortcut;
+pub use self::shortcut::*;
@@ -0,0 +1,142 @@
+use std::fmt;
+
+use crate::{KeyEvent, KeyState, Keycode};
+
+const KEYCODES: [Keycode; 30] = [
+    Keycode::Esc,
+    Keycode::F1,
+    Keycode::F2,
+    Keycode::F3,
+    Keycode::F4,
+    Keycode::F5,
+    Keycode::F6,
+    Keycode::F7,
+    Keycode::F8,
+    Keycode::F9,
+    Keycode::F10,
+    Keycode::F11,
+    Keycode::F12,
+    Keycode::PrintScreen,
+    Keycode::ScrollLock,
+    Keycode::Pause,
+    Keycode::Insert,
+    Keycode::Delete,
+    Keycode::Home,
+    Keycode::End,
+    Keycode::PageUp,
+    Keycode::PageDown,
+    Keycode::Left,
+    Keycode::Right,
+    Keycode::Up,
+    Keycode::Down,
+    Keycode::Backspace,
+    Keycode::Tab,
+    Keycode::Enter,
+    Keycode::CapsLock,
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShortcutKey {
+    Keycode(Keycode),
+    Char(char),
+}
+
+///
+/// Key combination executing a command.
+///
+/// Example:
+///
+/// window.add_shortcut(Shortcut::parse("Ctrl+S").unwrap(), vm.save.clone());
+/// window.add_shortcut(Shortcut::new(ShortcutKey::Keycode(Keycode::F5)), vm.refresh.clone());
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut {
+    pub key: ShortcutKey,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl Shortcut {
+    pub fn new(key: ShortcutKey) -> Self {
+        Shortcut {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    ///
+    /// Parses shortcut like "Ctrl+Shift+S", "Alt+F4" or "Delete".
+    ///
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = text.split('+').map(|part| part.trim()).collect();
+        let key = parts.pop()?;
+
+        let key = if key.chars().count() == 1 {
+            ShortcutKey::Char(key.chars().next()?.to_ascii_lowercase())
+        } else {
+            ShortcutKey::Keycode(
+                KEYCODES
+                    .iter()
+                    .find(|keycode| format!("{:?}", keycode).eq_ignore_ascii_case(key))?
+                    .clone(),
+            )
+        };
+
+        let mut shortcut = Shortcut::new(key);
+        for modifier in parts {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" => shortcut.ctrl = true,
+                "shift" => shortcut.shift = true,
+                "alt" => shortcut.alt = true,
+                _ => return None,
+            }
+        }
+        Some(shortcut)
+    }
+
+    pub fn matches(&self, key_event: &KeyEvent) -> bool {
+        if key_event.state != KeyState::Pressed
+            || key_event.modifiers.ctrl != self.ctrl
+            || key_event.modifiers.shift != self.shift
+            || key_event.modifiers.alt != self.alt
+        {
+            return false;
+        }
+
+        match &self.key {
+            ShortcutKey::Keycode(keycode) => key_event.keycode.as_ref() == Some(keycode),
+            ShortcutKey::Char(c) => {
+                let key_char = key_event.text.as_ref().and_then(|text| text.chars().next());
+                match key_char {
+                    // with Ctrl pressed letters can be reported as control characters
+                    Some(key_char @ '\u{1}'..='\u{1a}') if key_event.modifiers.ctrl => {
+                        (b'a' + key_char as u8 - 1) as char == c.to_ascii_lowercase()
+                    }
+                    Some(key_char) => key_char.eq_ignore_ascii_case(c),
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        match &self.key {
+            ShortcutKey::Keycode(keycode) => write!(f, "{:?}", keycode),
+            ShortcutKey::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+        }
+    }
+}
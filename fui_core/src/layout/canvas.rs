@@ -0,0 +1,297 @@
+use std::cell::RefCell;
+use std::f32;
+use std::rc::{Rc, Weak};
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, DrawingContext, EventContext, Point, Property,
+    Rect, Size, Style, StyledControl, ViewContext,
+};
+use drawing::primitive::Primitive;
+use typed_builder::TypedBuilder;
+
+//
+// Attached values
+//
+
+/// Distance between the left edges of the child and the Canvas.
+pub struct CanvasLeft;
+impl typemap::Key for CanvasLeft {
+    type Value = Property<f32>;
+}
+
+/// Distance between the top edges of the child and the Canvas.
+pub struct CanvasTop;
+impl typemap::Key for CanvasTop {
+    type Value = Property<f32>;
+}
+
+/// Distance between the right edges of the child and the Canvas.
+pub struct CanvasRight;
+impl typemap::Key for CanvasRight {
+    type Value = Property<f32>;
+}
+
+/// Distance between the bottom edges of the child and the Canvas.
+pub struct CanvasBottom;
+impl typemap::Key for CanvasBottom {
+    type Value = Property<f32>;
+}
+
+///
+/// Order of the child in the Canvas.
+///
+/// Children with higher values are drawn over (and hit before)
+/// children with lower values. The default value is 0.
+///
+pub struct ZIndex;
+impl typemap::Key for ZIndex {
+    type Value = i32;
+}
+
+///
+/// Places children at the given positions.
+///
+/// The position is set with the `CanvasLeft` / `CanvasRight` and `CanvasTop` / `CanvasBottom`
+/// attached values. When both of the opposite values are set, the child is stretched between them.
+/// Children have their measured sizes and are placed at the top left corner by default.
+///
+/// The positions are properties, so the children can be moved by changing them.
+///
+/// The attached values are named `CanvasLeft` etc. (not `Canvas::Left`),
+/// because the `ui!` macro accepts only identifiers as the names of the attached values.
+///
+/// Example:
+///
+/// ui! {
+///     Canvas {
+///         Rectangle { CanvasLeft: &vm.x, CanvasTop: &vm.y, ZIndex: 1 },
+///         Text { CanvasRight: 10.0f32, CanvasBottom: 10.0f32, text: "Overlay" },
+///     }
+/// }
+///
+#[derive(TypedBuilder)]
+pub struct Canvas {}
+
+impl Canvas {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<RefCell<dyn ControlObject>> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultCanvasStyle::new(
+                    DefaultCanvasStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    ///
+    /// Returns the children in the drawing order (by ZIndex).
+    ///
+    fn get_ordered_children(
+        control_context: &ControlContext,
+    ) -> Vec<Rc<RefCell<dyn ControlObject>>> {
+        let mut children: Vec<_> = control_context.get_children().into_iter().collect();
+        children.sort_by_key(|child| {
+            let child = child.borrow();
+            let map = child.get_context().get_attached_values();
+            map.get::<ZIndex>().cloned().unwrap_or(0)
+        });
+        children
+    }
+
+    ///
+    /// Returns the position and the size of the child on the axis
+    /// (the child is stretched when both of the sides are set).
+    ///
+    fn get_position(
+        start: Option<f32>,
+        end: Option<f32>,
+        size: f32,
+        available_size: f32,
+    ) -> (f32, f32) {
+        match (start, end) {
+            (Some(start), Some(end)) => (start, (available_size - start - end).max(0.0f32)),
+            (Some(start), None) => (start, size),
+            (None, Some(end)) => (available_size - end - size, size),
+            (None, None) => (0.0f32, size),
+        }
+    }
+}
+
+//
+// Default Canvas Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultCanvasStyleParams {}
+
+pub struct DefaultCanvasStyle {
+    // children with watched position
+    watched_children: Vec<Weak<RefCell<dyn ControlObject>>>,
+}
+
+impl DefaultCanvasStyle {
+    pub fn new(_params: DefaultCanvasStyleParams) -> Self {
+        DefaultCanvasStyle {
+            watched_children: Vec::new(),
+        }
+    }
+
+    ///
+    /// Makes the children (added since the last call) dirty when their position changes.
+    ///
+    fn watch_children(&mut self, control_context: &ControlContext) {
+        let mut watched_children = Vec::new();
+        for child in control_context.get_children().into_iter() {
+            let child_weak = Rc::downgrade(&child);
+            if !self
+                .watched_children
+                .iter()
+                .any(|watched_child| watched_child.ptr_eq(&child_weak))
+            {
+                let mut child = child.borrow_mut();
+                let child_context = child.get_context_mut();
+                child_context.dirty_watch_attached_property::<CanvasLeft, _>();
+                child_context.dirty_watch_attached_property::<CanvasTop, _>();
+                child_context.dirty_watch_attached_property::<CanvasRight, _>();
+                child_context.dirty_watch_attached_property::<CanvasBottom, _>();
+            }
+            watched_children.push(child_weak);
+        }
+        self.watched_children = watched_children;
+    }
+}
+
+impl Style<Canvas> for DefaultCanvasStyle {
+    fn setup(&mut self, _data: &mut Canvas, _control_context: &mut ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut Canvas,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        _data: &mut Canvas,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        _size: Size,
+    ) -> Size {
+        self.watch_children(control_context);
+
+        let mut width = 0.0f32;
+        let mut height = 0.0f32;
+
+        // the Canvas needs space for the children placed from the left top corner
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let mut child = child.borrow_mut();
+            child.measure(drawing_context, Size::new(f32::INFINITY, f32::INFINITY));
+
+            let child_rect = child.get_rect();
+            let map = child.get_context().get_attached_values();
+            let left = map
+                .get::<CanvasLeft>()
+                .map(|left| left.get())
+                .unwrap_or(0.0f32);
+            let top = map
+                .get::<CanvasTop>()
+                .map(|top| top.get())
+                .unwrap_or(0.0f32);
+
+            if map.get::<CanvasRight>().is_none() || map.get::<CanvasLeft>().is_some() {
+                width = width.max(left + child_rect.width);
+            }
+            if map.get::<CanvasBottom>().is_none() || map.get::<CanvasTop>().is_some() {
+                height = height.max(top + child_rect.height);
+            }
+        }
+
+        Size::new(width, height)
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut Canvas,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        rect: Rect,
+    ) {
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let mut child = child.borrow_mut();
+
+            let child_rect = child.get_rect();
+            let map = child.get_context().get_attached_values();
+            let (x, width) = Canvas::get_position(
+                map.get::<CanvasLeft>().map(|left| left.get()),
+                map.get::<CanvasRight>().map(|right| right.get()),
+                child_rect.width,
+                rect.width,
+            );
+            let (y, height) = Canvas::get_position(
+                map.get::<CanvasTop>().map(|top| top.get()),
+                map.get::<CanvasBottom>().map(|bottom| bottom.get()),
+                child_rect.height,
+                rect.height,
+            );
+
+            child.set_rect(
+                drawing_context,
+                Rect::new(rect.x + x, rect.y + y, width, height),
+            );
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &Canvas,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        if point.is_inside(&control_context.get_rect()) {
+            // the top-most child first
+            for child in Canvas::get_ordered_children(control_context).iter().rev() {
+                let c = child.borrow();
+                let rect = c.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = c.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &Canvas,
+        control_context: &ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        let mut vec = Vec::new();
+        let mut overlay = Vec::new();
+
+        for child in Canvas::get_ordered_children(control_context) {
+            let (mut vec2, mut overlay2) = child.borrow().to_primitives(drawing_context);
+            vec.append(&mut vec2);
+            overlay.append(&mut overlay2);
+        }
+
+        (vec, overlay)
+    }
+}
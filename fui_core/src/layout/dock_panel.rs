@@ -0,0 +1,264 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{
+    Alignment, ControlContext, ControlEvent, ControlObject, DrawingContext, EventContext,
+    HorizontalAlignment, Point, Property, Rect, Size, Style, StyledControl, VerticalAlignment,
+    ViewContext,
+};
+use drawing::primitive::Primitive;
+use typed_builder::TypedBuilder;
+
+//
+// Attached values
+//
+
+///
+/// Side of the DockPanel the child is docked to.
+///
+/// Children without the attached value are docked to the left.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Dock {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+impl typemap::Key for Dock {
+    type Value = Dock;
+}
+
+///
+/// Docks children to the sides of the panel, in order.
+///
+/// Every child takes the side of the space left by the previous children.
+/// With `last_child_fill` (the default) the last child fills the remaining space.
+///
+/// Example:
+///
+/// ui! {
+///     DockPanel {
+///         Horizontal { Dock: Dock::Top, ... },
+///         Text { Dock: Dock::Bottom, text: "Ready" },
+///         Vertical { Dock: Dock::Left, ... },
+///         Grid { ... },
+///     }
+/// }
+///
+#[derive(TypedBuilder)]
+pub struct DockPanel {
+    #[builder(default = true)]
+    pub last_child_fill: bool,
+}
+
+impl DockPanel {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        mut context: ViewContext,
+    ) -> Rc<RefCell<dyn ControlObject>> {
+        // set default alignment to Start
+        context
+            .attached_values
+            .entry::<HorizontalAlignment>()
+            .or_insert(Property::new(Alignment::Start));
+        context
+            .attached_values
+            .entry::<VerticalAlignment>()
+            .or_insert(Property::new(Alignment::Start));
+
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultDockPanelStyle::new(
+                    DefaultDockPanelStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    fn get_dock(child: &Rc<RefCell<dyn ControlObject>>) -> Dock {
+        let child = child.borrow();
+        let map = child.get_context().get_attached_values();
+        map.get::<Dock>().cloned().unwrap_or(Dock::Left)
+    }
+
+    ///
+    /// Index of the child filling the remaining space (if any).
+    ///
+    fn get_fill_index(&self, children_count: usize) -> Option<usize> {
+        if self.last_child_fill && children_count > 0 {
+            Some(children_count - 1)
+        } else {
+            None
+        }
+    }
+}
+
+//
+// Default DockPanel Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultDockPanelStyleParams {}
+
+pub struct DefaultDockPanelStyle;
+
+impl DefaultDockPanelStyle {
+    pub fn new(_params: DefaultDockPanelStyleParams) -> Self {
+        DefaultDockPanelStyle {}
+    }
+}
+
+impl Style<DockPanel> for DefaultDockPanelStyle {
+    fn setup(&mut self, _data: &mut DockPanel, _control_context: &mut ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut DockPanel,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        _data: &mut DockPanel,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        size: Size,
+    ) -> Size {
+        let children = control_context.get_children();
+
+        // size taken by the docked children
+        let mut accumulated_width = 0.0f32;
+        let mut accumulated_height = 0.0f32;
+
+        // size needed by the children in the other direction
+        let mut parent_width = 0.0f32;
+        let mut parent_height = 0.0f32;
+
+        for child in children.into_iter() {
+            let available_size = Size::new(
+                (size.width - accumulated_width).max(0.0f32),
+                (size.height - accumulated_height).max(0.0f32),
+            );
+            child.borrow_mut().measure(drawing_context, available_size);
+            let child_size = child.borrow().get_rect();
+
+            match DockPanel::get_dock(&child) {
+                Dock::Left | Dock::Right => {
+                    parent_height = parent_height.max(accumulated_height + child_size.height);
+                    accumulated_width += child_size.width;
+                }
+                Dock::Top | Dock::Bottom => {
+                    parent_width = parent_width.max(accumulated_width + child_size.width);
+                    accumulated_height += child_size.height;
+                }
+            }
+        }
+
+        Size::new(
+            parent_width.max(accumulated_width),
+            parent_height.max(accumulated_height),
+        )
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut DockPanel,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        rect: Rect,
+    ) {
+        let children = control_context.get_children();
+        let fill_index = data.get_fill_index(children.len());
+
+        // space not taken by the docked children
+        let mut left = rect.x;
+        let mut top = rect.y;
+        let mut right = rect.x + rect.width;
+        let mut bottom = rect.y + rect.height;
+
+        for (index, child) in children.into_iter().enumerate() {
+            let child_size = child.borrow().get_rect();
+            let remaining_width = (right - left).max(0.0f32);
+            let remaining_height = (bottom - top).max(0.0f32);
+
+            let child_rect = if Some(index) == fill_index {
+                Rect::new(left, top, remaining_width, remaining_height)
+            } else {
+                let width = child_size.width.min(remaining_width);
+                let height = child_size.height.min(remaining_height);
+                match DockPanel::get_dock(&child) {
+                    Dock::Left => {
+                        left += width;
+                        Rect::new(left - width, top, width, remaining_height)
+                    }
+                    Dock::Right => {
+                        right -= width;
+                        Rect::new(right, top, width, remaining_height)
+                    }
+                    Dock::Top => {
+                        top += height;
+                        Rect::new(left, top - height, remaining_width, height)
+                    }
+                    Dock::Bottom => {
+                        bottom -= height;
+                        Rect::new(left, bottom, remaining_width, height)
+                    }
+                }
+            };
+
+            child.borrow_mut().set_rect(drawing_context, child_rect);
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &DockPanel,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            for child in children.into_iter() {
+                let c = child.borrow();
+                let rect = c.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = c.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &DockPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        let mut vec = Vec::new();
+        let mut overlay = Vec::new();
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let (mut vec2, mut overlay2) = child.borrow().to_primitives(drawing_context);
+            vec.append(&mut vec2);
+            overlay.append(&mut overlay2);
+        }
+
+        (vec, overlay)
+    }
+}
@@ -0,0 +1,880 @@
+use std::cell::RefCell;
+use std::f32;
+use std::rc::Rc;
+
+use crate::{
+    Alignment, ControlContext, ControlEvent, ControlObject, DrawingContext, EventContext, Point,
+    Rect, Size, Style, StyledControl, ViewContext, Visible,
+};
+use drawing::primitive::Primitive;
+use typed_builder::TypedBuilder;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlexDirection {
+    Row,
+    RowReverse,
+    Column,
+    ColumnReverse,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlexWrap {
+    NoWrap,
+    Wrap,
+    WrapReverse,
+}
+
+///
+/// Distribution of the free space between the children in the line.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JustifyContent {
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+///
+/// Distribution of the free space between the lines.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AlignContent {
+    Start,
+    End,
+    Center,
+    Stretch,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+//
+// Attached values
+//
+
+///
+/// How much of the free space the child takes (relative to the other children).
+/// The default value is 0.
+///
+pub struct FlexGrow;
+impl typemap::Key for FlexGrow {
+    type Value = f32;
+}
+
+///
+/// How much the child shrinks when there is not enough space
+/// (relative to the other children, weighted by the basis).
+/// The default value is 1.
+///
+pub struct FlexShrink;
+impl typemap::Key for FlexShrink {
+    type Value = f32;
+}
+
+///
+/// Initial size of the child along the direction (the measured size by default).
+///
+pub struct FlexBasis;
+impl typemap::Key for FlexBasis {
+    type Value = f32;
+}
+
+///
+/// Overrides `align_items` of the Flex for the child.
+///
+pub struct AlignSelf;
+impl typemap::Key for AlignSelf {
+    type Value = Alignment;
+}
+
+///
+/// Layout implementing the CSS flexbox model.
+///
+/// Children are placed in the direction, optionally wrapped into multiple lines.
+/// Free space is distributed with the `FlexGrow` / `FlexShrink` attached values,
+/// what is left is distributed according to `justify_content` (in the lines)
+/// and `align_content` (between the lines).
+///
+/// Example:
+///
+/// ui! {
+///     Flex {
+///         gap: 4.0f32,
+///         align_items: Alignment::Center,
+///         Button { Text { text: "Open" } },
+///         Button { Text { text: "Save" } },
+///         Text { FlexGrow: 1.0f32, text: &vm.file_name },
+///         Button { Text { text: "Settings" } },
+///     }
+/// }
+///
+#[derive(TypedBuilder)]
+pub struct Flex {
+    #[builder(default = FlexDirection::Row)]
+    pub direction: FlexDirection,
+
+    #[builder(default = FlexWrap::NoWrap)]
+    pub wrap: FlexWrap,
+
+    #[builder(default = JustifyContent::Start)]
+    pub justify_content: JustifyContent,
+
+    /// Alignment of the children in the lines (in the cross direction).
+    #[builder(default = Alignment::Stretch)]
+    pub align_items: Alignment,
+
+    /// Used only when the children are wrapped.
+    #[builder(default = AlignContent::Stretch)]
+    pub align_content: AlignContent,
+
+    /// Space between the children and between the lines.
+    #[builder(default = 0.0f32)]
+    pub gap: f32,
+}
+
+impl Flex {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<RefCell<dyn ControlObject>> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultFlexStyle::new(
+                    DefaultFlexStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    fn is_row(&self) -> bool {
+        match self.direction {
+            FlexDirection::Row | FlexDirection::RowReverse => true,
+            FlexDirection::Column | FlexDirection::ColumnReverse => false,
+        }
+    }
+
+    ///
+    /// Returns (main, cross) sizes for the direction.
+    ///
+    fn main_cross(&self, size: Size) -> (f32, f32) {
+        if self.is_row() {
+            (size.width, size.height)
+        } else {
+            (size.height, size.width)
+        }
+    }
+
+    fn get_item(&self, child: &Rc<RefCell<dyn ControlObject>>) -> Option<FlexItem> {
+        let child = child.borrow();
+        let map = child.get_context().get_attached_values();
+
+        // invisible children are not part of the layout
+        if let Some(visible) = map.get::<Visible>() {
+            if !visible.get() {
+                return None;
+            }
+        }
+
+        let rect = child.get_rect();
+        let (main_size, cross_size) = self.main_cross(Size::new(rect.width, rect.height));
+        Some(FlexItem {
+            basis: map.get::<FlexBasis>().cloned().unwrap_or(main_size),
+            cross_size,
+            grow: map.get::<FlexGrow>().cloned().unwrap_or(0.0f32),
+            shrink: map.get::<FlexShrink>().cloned().unwrap_or(1.0f32),
+            align_self: map.get::<AlignSelf>().cloned(),
+        })
+    }
+
+    ///
+    /// Calculates rects of the items (relative to the Flex)
+    /// and the size needed by the items.
+    ///
+    fn calc_layout(&self, items: &[FlexItem], size: Size) -> (Vec<Rect>, Size) {
+        let (available_main, available_cross) = self.main_cross(size);
+
+        let lines = self.calc_lines(items, available_main);
+
+        // size needed by the items
+        let content_main = lines
+            .iter()
+            .map(|line| {
+                items[line.start..line.end]
+                    .iter()
+                    .map(|item| item.basis)
+                    .sum::<f32>()
+                    + self.get_gaps(line.end - line.start)
+            })
+            .fold(0.0f32, f32::max);
+        let mut line_cross_sizes: Vec<f32> = lines
+            .iter()
+            .map(|line| {
+                items[line.start..line.end]
+                    .iter()
+                    .map(|item| item.cross_size)
+                    .fold(0.0f32, f32::max)
+            })
+            .collect();
+        let content_cross = line_cross_sizes.iter().sum::<f32>() + self.get_gaps(lines.len());
+
+        let container_main = if available_main.is_finite() {
+            available_main
+        } else {
+            content_main
+        };
+        let container_cross = if available_cross.is_finite() {
+            available_cross
+        } else {
+            content_cross
+        };
+
+        // position of the lines
+        let mut line_positions = Vec::with_capacity(lines.len());
+        if let FlexWrap::NoWrap = self.wrap {
+            if let Some(line_cross) = line_cross_sizes.first_mut() {
+                *line_cross = container_cross;
+            }
+            line_positions.push(0.0f32);
+        } else {
+            let free_space = container_cross - content_cross;
+            let (mut position, spacing) = match self.align_content {
+                AlignContent::Stretch => {
+                    if free_space > 0.0f32 {
+                        for line_cross in line_cross_sizes.iter_mut() {
+                            *line_cross += free_space / lines.len() as f32;
+                        }
+                    }
+                    (0.0f32, 0.0f32)
+                }
+                AlignContent::Start => distribute(free_space, lines.len(), JustifyContent::Start),
+                AlignContent::End => distribute(free_space, lines.len(), JustifyContent::End),
+                AlignContent::Center => distribute(free_space, lines.len(), JustifyContent::Center),
+                AlignContent::SpaceBetween => {
+                    distribute(free_space, lines.len(), JustifyContent::SpaceBetween)
+                }
+                AlignContent::SpaceAround => {
+                    distribute(free_space, lines.len(), JustifyContent::SpaceAround)
+                }
+                AlignContent::SpaceEvenly => {
+                    distribute(free_space, lines.len(), JustifyContent::SpaceEvenly)
+                }
+            };
+            for line_cross in &line_cross_sizes {
+                line_positions.push(position);
+                position += line_cross + self.gap + spacing;
+            }
+        }
+
+        // position of the items in the lines
+        let mut rects = Vec::with_capacity(items.len());
+        for (line_index, line) in lines.iter().enumerate() {
+            let line_items = &items[line.start..line.end];
+            let line_cross = line_cross_sizes[line_index];
+            let line_position = line_positions[line_index];
+
+            let gaps = self.get_gaps(line_items.len());
+            let main_sizes = resolve_flexible_lengths(line_items, available_main - gaps);
+            let free_space = container_main - main_sizes.iter().sum::<f32>() - gaps;
+            let (mut main_position, spacing) =
+                distribute(free_space, line_items.len(), self.justify_content);
+
+            for (item, main_size) in line_items.iter().zip(main_sizes) {
+                let (cross_position, cross_size) = match item.align_self.unwrap_or(self.align_items)
+                {
+                    Alignment::Start => (0.0f32, item.cross_size),
+                    Alignment::Center => ((line_cross - item.cross_size) / 2.0f32, item.cross_size),
+                    Alignment::End => (line_cross - item.cross_size, item.cross_size),
+                    Alignment::Stretch => (0.0f32, line_cross),
+                };
+                let mut cross_position = line_position + cross_position;
+
+                let mut item_main_position = main_position;
+                if let FlexDirection::RowReverse | FlexDirection::ColumnReverse = self.direction {
+                    item_main_position = container_main - main_position - main_size;
+                }
+                if let FlexWrap::WrapReverse = self.wrap {
+                    cross_position = container_cross - cross_position - cross_size;
+                }
+
+                rects.push(if self.is_row() {
+                    Rect::new(item_main_position, cross_position, main_size, cross_size)
+                } else {
+                    Rect::new(cross_position, item_main_position, cross_size, main_size)
+                });
+
+                main_position += main_size + self.gap + spacing;
+            }
+        }
+
+        let content_size = if self.is_row() {
+            Size::new(content_main, content_cross)
+        } else {
+            Size::new(content_cross, content_main)
+        };
+
+        (rects, content_size)
+    }
+
+    ///
+    /// Breaks items into lines that fit the available main size.
+    ///
+    fn calc_lines(&self, items: &[FlexItem], available_main: f32) -> Vec<FlexLine> {
+        let mut lines = Vec::new();
+        let mut line = FlexLine { start: 0, end: 0 };
+        let mut line_main = 0.0f32;
+
+        for (index, item) in items.iter().enumerate() {
+            if let FlexWrap::Wrap | FlexWrap::WrapReverse = self.wrap {
+                if line.end > line.start && line_main + self.gap + item.basis > available_main {
+                    lines.push(line);
+                    line = FlexLine {
+                        start: index,
+                        end: index,
+                    };
+                }
+            }
+
+            if line.end > line.start {
+                line_main += self.gap;
+            } else {
+                line_main = 0.0f32;
+            }
+            line_main += item.basis;
+            line.end = index + 1;
+        }
+
+        if line.end > line.start {
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    fn get_gaps(&self, count: usize) -> f32 {
+        self.gap * count.saturating_sub(1) as f32
+    }
+}
+
+struct FlexItem {
+    basis: f32,
+    cross_size: f32,
+    grow: f32,
+    shrink: f32,
+    align_self: Option<Alignment>,
+}
+
+struct FlexLine {
+    start: usize,
+    end: usize,
+}
+
+///
+/// Returns the offset of the first item and the additional space between the items.
+///
+fn distribute(free_space: f32, count: usize, justify_content: JustifyContent) -> (f32, f32) {
+    let count = count as f32;
+    match justify_content {
+        JustifyContent::Start => (0.0f32, 0.0f32),
+        JustifyContent::End => (free_space, 0.0f32),
+        JustifyContent::Center => (free_space / 2.0f32, 0.0f32),
+        JustifyContent::SpaceBetween => {
+            if free_space > 0.0f32 && count > 1.0f32 {
+                (0.0f32, free_space / (count - 1.0f32))
+            } else {
+                (0.0f32, 0.0f32)
+            }
+        }
+        JustifyContent::SpaceAround => {
+            if free_space > 0.0f32 {
+                (free_space / count / 2.0f32, free_space / count)
+            } else {
+                (free_space / 2.0f32, 0.0f32)
+            }
+        }
+        JustifyContent::SpaceEvenly => {
+            if free_space > 0.0f32 {
+                (free_space / (count + 1.0f32), free_space / (count + 1.0f32))
+            } else {
+                (free_space / 2.0f32, 0.0f32)
+            }
+        }
+    }
+}
+
+///
+/// Returns main sizes of the items in the line (CSS "Resolving Flexible Lengths").
+///
+/// Items cannot be shrunk below zero.
+///
+fn resolve_flexible_lengths(items: &[FlexItem], available_main: f32) -> Vec<f32> {
+    let mut sizes: Vec<f32> = items.iter().map(|item| item.basis).collect();
+    if !available_main.is_finite() {
+        return sizes;
+    }
+
+    let initial_free_space = available_main - sizes.iter().sum::<f32>();
+    let is_growing = initial_free_space > 0.0f32;
+    let get_factor = |item: &FlexItem| {
+        if is_growing {
+            item.grow
+        } else {
+            item.shrink * item.basis
+        }
+    };
+
+    let mut is_frozen: Vec<bool> = items
+        .iter()
+        .map(|item| get_factor(item) <= 0.0f32)
+        .collect();
+    loop {
+        let mut free_space = available_main;
+        let mut factor_sum = 0.0f32;
+        let mut flex_factor_sum = 0.0f32;
+        for (index, item) in items.iter().enumerate() {
+            if is_frozen[index] {
+                free_space -= sizes[index];
+            } else {
+                free_space -= item.basis;
+                factor_sum += get_factor(item);
+                flex_factor_sum += if is_growing { item.grow } else { item.shrink };
+            }
+        }
+        if factor_sum <= 0.0f32 {
+            break;
+        }
+
+        // only part of the free space is distributed when the sum of the factors is less than one
+        if flex_factor_sum < 1.0f32 {
+            let limited_free_space = initial_free_space * flex_factor_sum;
+            if limited_free_space.abs() < free_space.abs() {
+                free_space = limited_free_space;
+            }
+        }
+
+        let mut is_violated = false;
+        for (index, item) in items.iter().enumerate() {
+            if !is_frozen[index] {
+                let size = item.basis + free_space * get_factor(item) / factor_sum;
+                if size < 0.0f32 {
+                    sizes[index] = 0.0f32;
+                    is_frozen[index] = true;
+                    is_violated = true;
+                } else {
+                    sizes[index] = size;
+                }
+            }
+        }
+
+        if !is_violated {
+            break;
+        }
+    }
+
+    sizes
+}
+
+//
+// Default Flex Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultFlexStyleParams {}
+
+pub struct DefaultFlexStyle;
+
+impl DefaultFlexStyle {
+    pub fn new(_params: DefaultFlexStyleParams) -> Self {
+        DefaultFlexStyle {}
+    }
+}
+
+impl Style<Flex> for DefaultFlexStyle {
+    fn setup(&mut self, _data: &mut Flex, _control_context: &mut ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut Flex,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut Flex,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        size: Size,
+    ) -> Size {
+        // children are measured with their content size in the direction
+        let available_size = if data.is_row() {
+            Size::new(f32::INFINITY, size.height)
+        } else {
+            Size::new(size.width, f32::INFINITY)
+        };
+
+        let mut items = Vec::new();
+        for child in control_context.get_children().into_iter() {
+            child.borrow_mut().measure(drawing_context, available_size);
+            items.extend(data.get_item(&child));
+        }
+
+        data.calc_layout(&items, size).1
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut Flex,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        rect: Rect,
+    ) {
+        let mut children = Vec::new();
+        let mut items = Vec::new();
+        for child in control_context.get_children().into_iter() {
+            match data.get_item(&child) {
+                Some(item) => {
+                    children.push(child);
+                    items.push(item);
+                }
+                None => child.borrow_mut().set_rect(drawing_context, Rect::empty()),
+            }
+        }
+
+        let (rects, _) = data.calc_layout(&items, Size::new(rect.width, rect.height));
+        for (child, child_rect) in children.into_iter().zip(rects) {
+            child.borrow_mut().set_rect(
+                drawing_context,
+                Rect::new(
+                    rect.x + child_rect.x,
+                    rect.y + child_rect.y,
+                    child_rect.width,
+                    child_rect.height,
+                ),
+            );
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &Flex,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            for child in children.into_iter() {
+                let c = child.borrow();
+                let rect = c.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = c.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &Flex,
+        control_context: &ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        let mut vec = Vec::new();
+        let mut overlay = Vec::new();
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let (mut vec2, mut overlay2) = child.borrow().to_primitives(drawing_context);
+            vec.append(&mut vec2);
+            overlay.append(&mut overlay2);
+        }
+
+        (vec, overlay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(basis: f32, cross_size: f32) -> FlexItem {
+        FlexItem {
+            basis,
+            cross_size,
+            grow: 0.0f32,
+            shrink: 1.0f32,
+            align_self: None,
+        }
+    }
+
+    fn grow(basis: f32, grow: f32) -> FlexItem {
+        FlexItem {
+            grow,
+            ..item(basis, 10.0f32)
+        }
+    }
+
+    fn shrink(basis: f32, shrink: f32) -> FlexItem {
+        FlexItem {
+            shrink,
+            ..item(basis, 10.0f32)
+        }
+    }
+
+    fn align(cross_size: f32, align_self: Alignment) -> FlexItem {
+        FlexItem {
+            align_self: Some(align_self),
+            ..item(50.0f32, cross_size)
+        }
+    }
+
+    fn layout(
+        flex: Flex,
+        items: &[FlexItem],
+        width: f32,
+        height: f32,
+    ) -> Vec<(f32, f32, f32, f32)> {
+        flex.calc_layout(items, Size::new(width, height))
+            .0
+            .iter()
+            .map(|rect| (rect.x, rect.y, rect.width, rect.height))
+            .collect()
+    }
+
+    #[test]
+    fn test_grow() {
+        let rects = layout(
+            Flex::builder().build(),
+            &[grow(100.0, 1.0), grow(100.0, 2.0), grow(100.0, 1.0)],
+            600.0,
+            10.0,
+        );
+        assert_eq!(
+            rects,
+            vec![
+                (0.0, 0.0, 175.0, 10.0),
+                (175.0, 0.0, 250.0, 10.0),
+                (425.0, 0.0, 175.0, 10.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grow_factors_less_than_one() {
+        let rects = layout(
+            Flex::builder().build(),
+            &[grow(100.0, 0.25), grow(100.0, 0.25)],
+            600.0,
+            10.0,
+        );
+        assert_eq!(
+            rects,
+            vec![(0.0, 0.0, 200.0, 10.0), (200.0, 0.0, 200.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn test_shrink() {
+        let rects = layout(
+            Flex::builder().build(),
+            &[shrink(100.0, 1.0), shrink(300.0, 1.0), shrink(100.0, 0.0)],
+            300.0,
+            10.0,
+        );
+        assert_eq!(
+            rects,
+            vec![
+                (0.0, 0.0, 50.0, 10.0),
+                (50.0, 0.0, 150.0, 10.0),
+                (200.0, 0.0, 100.0, 10.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shrink_to_zero() {
+        let rects = layout(
+            Flex::builder().build(),
+            &[shrink(10.0, 100.0), shrink(100.0, 1.0)],
+            50.0,
+            10.0,
+        );
+        assert_eq!(rects, vec![(0.0, 0.0, 0.0, 10.0), (0.0, 0.0, 50.0, 10.0)]);
+    }
+
+    #[test]
+    fn test_justify_content() {
+        let items = [item(100.0, 10.0), item(100.0, 10.0)];
+        let positions = |justify_content| {
+            layout(
+                Flex::builder().justify_content(justify_content).build(),
+                &items,
+                500.0,
+                10.0,
+            )
+            .iter()
+            .map(|rect| rect.0)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(positions(JustifyContent::Start), vec![0.0, 100.0]);
+        assert_eq!(positions(JustifyContent::End), vec![300.0, 400.0]);
+        assert_eq!(positions(JustifyContent::Center), vec![150.0, 250.0]);
+        assert_eq!(positions(JustifyContent::SpaceBetween), vec![0.0, 400.0]);
+        assert_eq!(positions(JustifyContent::SpaceAround), vec![75.0, 325.0]);
+        assert_eq!(positions(JustifyContent::SpaceEvenly), vec![100.0, 300.0]);
+    }
+
+    #[test]
+    fn test_align_items() {
+        let rects = layout(
+            Flex::builder().align_items(Alignment::Center).build(),
+            &[
+                item(50.0, 20.0),
+                align(20.0, Alignment::Start),
+                align(20.0, Alignment::End),
+                align(20.0, Alignment::Stretch),
+            ],
+            400.0,
+            100.0,
+        );
+        assert_eq!(
+            rects,
+            vec![
+                (0.0, 40.0, 50.0, 20.0),
+                (50.0, 0.0, 50.0, 20.0),
+                (100.0, 80.0, 50.0, 20.0),
+                (150.0, 0.0, 50.0, 100.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrap_with_gap() {
+        let rects = layout(
+            Flex::builder()
+                .wrap(FlexWrap::Wrap)
+                .gap(10.0)
+                .justify_content(JustifyContent::Center)
+                .align_content(AlignContent::Start)
+                .build(),
+            &[item(100.0, 20.0), item(100.0, 30.0), item(100.0, 20.0)],
+            250.0,
+            200.0,
+        );
+        assert_eq!(
+            rects,
+            vec![
+                (20.0, 0.0, 100.0, 30.0),
+                (130.0, 0.0, 100.0, 30.0),
+                (75.0, 40.0, 100.0, 20.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_align_content() {
+        let items = [item(100.0, 20.0), item(100.0, 20.0), item(100.0, 20.0)];
+        let positions = |align_content| {
+            layout(
+                Flex::builder()
+                    .wrap(FlexWrap::Wrap)
+                    .align_content(align_content)
+                    .build(),
+                &items,
+                100.0,
+                120.0,
+            )
+            .iter()
+            .map(|rect| (rect.1, rect.3))
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            positions(AlignContent::Stretch),
+            vec![(0.0, 40.0), (40.0, 40.0), (80.0, 40.0)]
+        );
+        assert_eq!(
+            positions(AlignContent::End),
+            vec![(60.0, 20.0), (80.0, 20.0), (100.0, 20.0)]
+        );
+        assert_eq!(
+            positions(AlignContent::SpaceBetween),
+            vec![(0.0, 20.0), (50.0, 20.0), (100.0, 20.0)]
+        );
+        assert_eq!(
+            positions(AlignContent::SpaceAround),
+            vec![(10.0, 20.0), (50.0, 20.0), (90.0, 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_reverse() {
+        let items = [item(100.0, 20.0), item(50.0, 30.0)];
+
+        let rects = layout(
+            Flex::builder().direction(FlexDirection::RowReverse).build(),
+            &items,
+            400.0,
+            30.0,
+        );
+        assert_eq!(
+            rects,
+            vec![(300.0, 0.0, 100.0, 30.0), (250.0, 0.0, 50.0, 30.0)]
+        );
+
+        let rects = layout(
+            Flex::builder()
+                .direction(FlexDirection::ColumnReverse)
+                .align_items(Alignment::Start)
+                .build(),
+            &items,
+            40.0,
+            400.0,
+        );
+        assert_eq!(
+            rects,
+            vec![(0.0, 300.0, 20.0, 100.0), (0.0, 250.0, 30.0, 50.0)]
+        );
+
+        let rects = layout(
+            Flex::builder()
+                .wrap(FlexWrap::WrapReverse)
+                .align_content(AlignContent::Start)
+                .build(),
+            &items,
+            120.0,
+            100.0,
+        );
+        assert_eq!(
+            rects,
+            vec![(0.0, 80.0, 100.0, 20.0), (0.0, 50.0, 50.0, 30.0)]
+        );
+    }
+
+    #[test]
+    fn test_content_size() {
+        let flex = Flex::builder().wrap(FlexWrap::Wrap).gap(5.0).build();
+        let items = [item(100.0, 20.0), item(50.0, 30.0), item(80.0, 10.0)];
+
+        let (_, size) = flex.calc_layout(&items, Size::new(f32::INFINITY, f32::INFINITY));
+        assert_eq!((size.width, size.height), (240.0, 30.0));
+
+        let (_, size) = flex.calc_layout(&items, Size::new(200.0, f32::INFINITY));
+        assert_eq!((size.width, size.height), (155.0, 45.0));
+    }
+}
@@ -32,7 +32,7 @@ use std::rc::Rc;
 
 use crate::{
     round_layout_value, Children, ControlContext, ControlEvent, ControlObject, DrawingContext,
-    EventContext, Point, Rect, Size, Style, StyledControl, ViewContext,
+    EventContext, Point, Property, Rect, SharedSizeGroups, Size, Style, StyledControl, ViewContext,
 };
 use drawing::primitive::Primitive;
 use typed_builder::TypedBuilder;
@@ -74,6 +74,9 @@ struct DefinitionBase {
 
     // offset of the DefinitionBase from left / top corner (assuming LTR case)
     pub final_offset: f32,
+
+    // name of the shared size group (min_size includes the size of the group after measure)
+    pub shared_group: Option<String>,
 }
 
 impl DefinitionBase {
@@ -82,6 +85,7 @@ impl DefinitionBase {
         user_min_size: f32,
         user_max_size: f32,
         treat_fill_as_auto: bool,
+        shared_group: Option<String>,
     ) -> DefinitionBase {
         let mut user_min_size = user_min_size;
         let user_size_value;
@@ -114,6 +118,7 @@ impl DefinitionBase {
             measure_size: user_min_size.max(user_size_value.min(user_max_size)),
             size_cache: 0.0f32,
             final_offset: 0.0f32,
+            shared_group,
         }
     }
 
@@ -122,7 +127,6 @@ impl DefinitionBase {
     }
 
     pub fn get_preferred_size(&self) -> f32 {
-        // may require change when SharedSizeGroup attribute is implemented
         if let Length::Auto = self.user_size {
             self.min_size
         } else {
@@ -131,13 +135,11 @@ impl DefinitionBase {
     }
 
     pub fn get_min_size_for_arrange(&self) -> f32 {
-        // may require change when SharedSizeGroup attribute is implemented
         self.min_size
     }
 
     pub fn is_shared(&self) -> bool {
-        // may require change when SharedSizeGroup attribute is implemented
-        false
+        self.shared_group.is_some()
     }
 }
 
@@ -163,7 +165,7 @@ struct CellCache {
 
 pub struct Row;
 impl typemap::Key for Row {
-    type Value = i32;
+    type Value = Property<i32>;
 }
 
 pub struct RowSpan;
@@ -173,7 +175,7 @@ impl typemap::Key for RowSpan {
 
 pub struct Column;
 impl typemap::Key for Column {
-    type Value = i32;
+    type Value = Property<i32>;
 }
 
 pub struct ColumnSpan;
@@ -199,11 +201,13 @@ pub struct Grid {
     #[builder(default = Length::Fill(1.0f32))]
     pub default_height: Length,
 
-    #[builder(default = Vec::new())]
-    pub widths: Vec<(i32, Length)>,
+    /// Widths of the columns (can be changed at runtime, for example by `GridSplitter`).
+    #[builder(default = Property::new(Vec::new()))]
+    pub widths: Property<Vec<(i32, Length)>>,
 
-    #[builder(default = Vec::new())]
-    pub heights: Vec<(i32, Length)>,
+    /// Heights of the rows (can be changed at runtime, for example by `GridSplitter`).
+    #[builder(default = Property::new(Vec::new()))]
+    pub heights: Property<Vec<(i32, Length)>>,
 
     #[builder(default = 0.0f32)]
     pub default_min_width: f32,
@@ -228,6 +232,25 @@ pub struct Grid {
 
     #[builder(default = Vec::new())]
     pub max_heights: Vec<(i32, f32)>,
+
+    /// Names of the shared size groups of the columns.
+    /// Columns of the same group have the same width in all the grids of `SharedSizeScope`.
+    /// Shared columns are sized to content, so `Length::Fill` is treated as `Length::Auto`.
+    #[builder(default = Vec::new())]
+    pub shared_width_groups: Vec<(i32, String)>,
+
+    /// Names of the shared size groups of the rows.
+    /// Rows of the same group have the same height in all the grids of `SharedSizeScope`.
+    /// Shared rows are sized to content, so `Length::Fill` is treated as `Length::Auto`.
+    #[builder(default = Vec::new())]
+    pub shared_height_groups: Vec<(i32, String)>,
+
+    // final sizes of the columns and rows (from the last layout)
+    #[builder(default, setter(skip))]
+    actual_widths: Vec<f32>,
+
+    #[builder(default, setter(skip))]
+    actual_heights: Vec<f32>,
 }
 
 impl Grid {
@@ -246,6 +269,53 @@ impl Grid {
             context,
         )
     }
+
+    pub fn get_width(&self, column: i32) -> Length {
+        Self::get_value(&self.widths.get(), column, self.default_width)
+    }
+
+    pub fn get_height(&self, row: i32) -> Length {
+        Self::get_value(&self.heights.get(), row, self.default_height)
+    }
+
+    pub fn get_min_width(&self, column: i32) -> f32 {
+        Self::get_value(&self.min_widths, column, self.default_min_width)
+    }
+
+    pub fn get_min_height(&self, row: i32) -> f32 {
+        Self::get_value(&self.min_heights, row, self.default_min_height)
+    }
+
+    pub fn get_max_width(&self, column: i32) -> f32 {
+        Self::get_value(&self.max_widths, column, self.default_max_width)
+    }
+
+    pub fn get_max_height(&self, row: i32) -> f32 {
+        Self::get_value(&self.max_heights, row, self.default_max_height)
+    }
+
+    ///
+    /// Returns final widths of the columns (from the last layout).
+    ///
+    pub fn get_actual_widths(&self) -> &[f32] {
+        &self.actual_widths
+    }
+
+    ///
+    /// Returns final heights of the rows (from the last layout).
+    ///
+    pub fn get_actual_heights(&self) -> &[f32] {
+        &self.actual_heights
+    }
+
+    fn get_value<T: Copy>(values: &[(i32, T)], index: i32, default_value: T) -> T {
+        values
+            .iter()
+            .rev()
+            .find(|(i, _)| *i == index)
+            .map(|(_, value)| *value)
+            .unwrap_or(default_value)
+    }
 }
 
 //
@@ -291,9 +361,9 @@ impl DefaultGridStyle {
 
             let max_row = if let Some(row) = map.get::<Row>() {
                 if let Some(row_span) = map.get::<RowSpan>() {
-                    row + row_span - 1
+                    row.get() + row_span - 1
                 } else {
-                    *row
+                    row.get()
                 }
             } else {
                 -1
@@ -302,9 +372,9 @@ impl DefaultGridStyle {
 
             let max_column = if let Some(column) = map.get::<Column>() {
                 if let Some(column_span) = map.get::<ColumnSpan>() {
-                    column + column_span - 1
+                    column.get() + column_span - 1
                 } else {
-                    *column
+                    column.get()
                 }
             } else {
                 -1
@@ -355,20 +425,24 @@ impl DefaultGridStyle {
         let mut widths = Vec::with_capacity(number_of_columns);
         let mut min_widths = Vec::with_capacity(number_of_columns);
         let mut max_widths = Vec::with_capacity(number_of_columns);
+        let mut shared_width_groups = Vec::with_capacity(number_of_columns);
         let mut heights = Vec::with_capacity(number_of_rows);
         let mut min_heights = Vec::with_capacity(number_of_rows);
         let mut max_heights = Vec::with_capacity(number_of_rows);
+        let mut shared_height_groups = Vec::with_capacity(number_of_rows);
         for _ in 0..number_of_columns {
             widths.push(data.default_width);
             min_widths.push(data.default_min_width);
             max_widths.push(data.default_max_width);
+            shared_width_groups.push(None);
         }
         for _ in 0..number_of_rows {
             heights.push(data.default_height);
             min_heights.push(data.default_min_height);
             max_heights.push(data.default_max_height);
+            shared_height_groups.push(None);
         }
-        for (column, width) in &data.widths {
+        for (column, width) in &data.widths.get() {
             if *column >= 0 && *column <= widths.len() as i32 {
                 widths[*column as usize] = *width;
             }
@@ -383,7 +457,7 @@ impl DefaultGridStyle {
                 max_widths[*column as usize] = *max_width;
             }
         }
-        for (row, height) in &data.heights {
+        for (row, height) in &data.heights.get() {
             if *row >= 0 && *row <= heights.len() as i32 {
                 heights[*row as usize] = *height;
             }
@@ -398,26 +472,64 @@ impl DefaultGridStyle {
                 max_heights[*row as usize] = *max_height;
             }
         }
+        for (column, group) in &data.shared_width_groups {
+            if *column >= 0 && *column < shared_width_groups.len() as i32 {
+                shared_width_groups[*column as usize] = Some(group.clone());
+            }
+        }
+        for (row, group) in &data.shared_height_groups {
+            if *row >= 0 && *row < shared_height_groups.len() as i32 {
+                shared_height_groups[*row as usize] = Some(group.clone());
+            }
+        }
 
         self.definitions_u = Vec::new();
-        for i in 0..number_of_columns {
-            let definition =
-                DefinitionBase::new(widths[i], min_widths[i], max_widths[i], size_to_content_u);
+        for (i, shared_group) in shared_width_groups.into_iter().enumerate() {
+            let definition = DefinitionBase::new(
+                Self::get_shared_length(widths[i], &shared_group),
+                min_widths[i],
+                max_widths[i],
+                size_to_content_u,
+                shared_group,
+            );
             self.definitions_u.push(definition);
         }
 
         self.definitions_v = Vec::new();
-        for i in 0..number_of_rows {
+        for (i, shared_group) in shared_height_groups.into_iter().enumerate() {
             let definition = DefinitionBase::new(
-                heights[i],
+                Self::get_shared_length(heights[i], &shared_group),
                 min_heights[i],
                 max_heights[i],
                 size_to_content_v,
+                shared_group,
             );
             self.definitions_v.push(definition);
         }
     }
 
+    ///
+    /// Shared definitions are sized to content (Fill is treated as Auto).
+    ///
+    fn get_shared_length(length: Length, shared_group: &Option<String>) -> Length {
+        match (length, shared_group) {
+            (Length::Fill(_), Some(_)) => Length::Auto,
+            (length, _) => length,
+        }
+    }
+
+    ///
+    /// Reports sizes of the shared definitions to the groups
+    /// and resizes the definitions to the sizes of the groups.
+    ///
+    fn apply_shared_sizes(definitions: &mut [DefinitionBase], groups: &mut SharedSizeGroups) {
+        for definition in definitions {
+            if let Some(ref group) = definition.shared_group {
+                definition.min_size = groups.update_size(group, definition.min_size);
+            }
+        }
+    }
+
     fn prepare_cell_cache(&mut self, data: &Grid, children: &Children) {
         self.has_fill_cells_u = false;
         self.has_fill_cells_v = false;
@@ -439,10 +551,10 @@ impl DefaultGridStyle {
             let child = child.borrow();
             let map = child.get_context().get_attached_values();
             if let Some(row) = map.get::<Row>() {
-                row_index = *row;
+                row_index = row.get();
             }
             if let Some(column) = map.get::<Column>() {
-                column_index = *column;
+                column_index = column.get();
             }
             if let Some(rspan) = map.get::<RowSpan>() {
                 row_span = *rspan;
@@ -1459,7 +1571,10 @@ impl DefaultGridStyle {
 }
 
 impl Style<Grid> for DefaultGridStyle {
-    fn setup(&mut self, _data: &mut Grid, _control_context: &mut ControlContext) {}
+    fn setup(&mut self, data: &mut Grid, control_context: &mut ControlContext) {
+        control_context.dirty_watch_property(&data.widths);
+        control_context.dirty_watch_property(&data.heights);
+    }
 
     fn handle_event(
         &mut self,
@@ -1636,6 +1751,12 @@ impl Style<Grid> for DefaultGridStyle {
                 false,
             );
 
+            if let Some(groups) = SharedSizeGroups::current() {
+                let mut groups = groups.borrow_mut();
+                Self::apply_shared_sizes(&mut self.definitions_u, &mut groups);
+                Self::apply_shared_sizes(&mut self.definitions_v, &mut groups);
+            }
+
             grid_desired_size.width = Self::calculate_desired_size(&self.definitions_u);
             grid_desired_size.height = Self::calculate_desired_size(&self.definitions_v);
         }
@@ -1645,7 +1766,7 @@ impl Style<Grid> for DefaultGridStyle {
 
     fn set_rect(
         &mut self,
-        _data: &mut Grid,
+        data: &mut Grid,
         control_context: &mut ControlContext,
         drawing_context: &mut dyn DrawingContext,
         rect: Rect,
@@ -1653,6 +1774,9 @@ impl Style<Grid> for DefaultGridStyle {
         let children = control_context.get_children();
 
         if self.definitions_u.len() == 0 && self.definitions_v.len() == 0 {
+            data.actual_widths.clear();
+            data.actual_heights.clear();
+
             for child in children.into_iter() {
                 child.borrow_mut().set_rect(drawing_context, rect);
             }
@@ -1660,6 +1784,9 @@ impl Style<Grid> for DefaultGridStyle {
             Self::set_final_size(&mut self.definitions_u, rect.width, true);
             Self::set_final_size(&mut self.definitions_v, rect.height, false);
 
+            data.actual_widths = self.definitions_u.iter().map(|d| d.size_cache).collect();
+            data.actual_heights = self.definitions_v.iter().map(|d| d.size_cache).collect();
+
             for cell in self
                 .cell_group_1
                 .iter()
@@ -1,3 +1,12 @@
+mod canvas;
+pub use canvas::*;
+
+mod dock_panel;
+pub use dock_panel::*;
+
+mod flex;
+pub use flex::*;
+
 mod horizontal;
 pub use horizontal::*;
 
@@ -7,8 +16,14 @@ pub use grid::*;
 mod relative_layout;
 pub use relative_layout::*;
 
+mod shared_size_scope;
+pub use shared_size_scope::*;
+
 mod stack_panel;
 pub use stack_panel::*;
 
 mod vertical;
 pub use vertical::*;
+
+mod wrap_panel;
+pub use wrap_panel::*;
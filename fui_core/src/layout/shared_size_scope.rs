@@ -0,0 +1,324 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, DrawingContext, EventContext, Point, Rect, Size,
+    Style, StyledControl, ViewContext,
+};
+use drawing::primitive::Primitive;
+use typed_builder::TypedBuilder;
+
+thread_local! {
+    // groups of the scopes being measured (the inner scope is the last one)
+    static CURRENT_SHARED_SIZE_GROUPS: RefCell<Vec<Rc<RefCell<SharedSizeGroups>>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+///
+/// Maximum number of the measure passes of the scope.
+///
+/// The children are measured again when the size of some group changes.
+///
+const MAX_MEASURE_PASSES: usize = 3;
+
+///
+/// Sizes of the shared size groups of the scope.
+///
+pub(crate) struct SharedSizeGroups {
+    groups: HashMap<String, SharedSizeGroup>,
+}
+
+struct SharedSizeGroup {
+    // size from the previous measure pass
+    size: f32,
+
+    // size of the largest member in the current measure pass
+    measured_size: f32,
+}
+
+impl SharedSizeGroups {
+    fn new() -> Self {
+        SharedSizeGroups {
+            groups: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Returns groups of the inner scope being measured.
+    ///
+    pub fn current() -> Option<Rc<RefCell<SharedSizeGroups>>> {
+        CURRENT_SHARED_SIZE_GROUPS.with(|current| current.borrow().last().cloned())
+    }
+
+    ///
+    /// Reports size of the member and returns size of the group.
+    ///
+    pub fn update_size(&mut self, group: &str, size: f32) -> f32 {
+        let group = self
+            .groups
+            .entry(group.to_string())
+            .or_insert(SharedSizeGroup {
+                size: 0.0f32,
+                measured_size: 0.0f32,
+            });
+        group.measured_size = group.measured_size.max(size);
+        group.size.max(size)
+    }
+
+    fn begin_pass(&mut self) {
+        for group in self.groups.values_mut() {
+            group.measured_size = 0.0f32;
+        }
+    }
+
+    ///
+    /// Updates sizes of the groups and returns true if any of them has changed.
+    ///
+    fn end_pass(&mut self) -> bool {
+        // groups without members are removed
+        self.groups.retain(|_, group| group.measured_size > 0.0f32);
+
+        let mut is_changed = false;
+        for group in self.groups.values_mut() {
+            if group.size != group.measured_size {
+                group.size = group.measured_size;
+                is_changed = true;
+            }
+        }
+        is_changed
+    }
+}
+
+///
+/// Scope of the Grid shared size groups.
+///
+/// Columns (and rows) with the same shared size group in all the grids inside the scope
+/// have the same size - the size of the largest one. Grids are laid out again
+/// when the size of any member changes.
+///
+/// Example:
+///
+/// ui! {
+///     SharedSizeScope {
+///         Vertical {
+///             Grid {
+///                 columns: 2,
+///                 shared_width_groups: vec![(0, "Label".to_string())],
+///                 Text { text: "Name" }, TextBox { text: &vm.name },
+///             },
+///             Grid {
+///                 columns: 2,
+///                 shared_width_groups: vec![(0, "Label".to_string())],
+///                 Text { text: "E-mail address" }, TextBox { text: &vm.email },
+///             },
+///         }
+///     }
+/// }
+///
+#[derive(TypedBuilder)]
+pub struct SharedSizeScope {}
+
+impl SharedSizeScope {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<RefCell<dyn ControlObject>> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultSharedSizeScopeStyle::new(
+                    DefaultSharedSizeScopeStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+//
+// Default SharedSizeScope Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultSharedSizeScopeStyleParams {}
+
+pub struct DefaultSharedSizeScopeStyle {
+    groups: Rc<RefCell<SharedSizeGroups>>,
+}
+
+impl DefaultSharedSizeScopeStyle {
+    pub fn new(_params: DefaultSharedSizeScopeStyleParams) -> Self {
+        DefaultSharedSizeScopeStyle {
+            groups: Rc::new(RefCell::new(SharedSizeGroups::new())),
+        }
+    }
+
+    fn measure_children(
+        &self,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        size: Size,
+    ) -> Size {
+        let mut desired_size = Size::new(0.0f32, 0.0f32);
+
+        CURRENT_SHARED_SIZE_GROUPS.with(|current| current.borrow_mut().push(self.groups.clone()));
+        for child in control_context.get_children().into_iter() {
+            let mut child = child.borrow_mut();
+            child.measure(drawing_context, size);
+            let child_rect = child.get_rect();
+            desired_size.width = desired_size.width.max(child_rect.width);
+            desired_size.height = desired_size.height.max(child_rect.height);
+        }
+        CURRENT_SHARED_SIZE_GROUPS.with(|current| current.borrow_mut().pop());
+
+        desired_size
+    }
+}
+
+impl Style<SharedSizeScope> for DefaultSharedSizeScopeStyle {
+    fn setup(&mut self, _data: &mut SharedSizeScope, _control_context: &mut ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut SharedSizeScope,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        _data: &mut SharedSizeScope,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        size: Size,
+    ) -> Size {
+        let mut desired_size = Size::new(0.0f32, 0.0f32);
+        for _ in 0..MAX_MEASURE_PASSES {
+            self.groups.borrow_mut().begin_pass();
+            desired_size = self.measure_children(control_context, drawing_context, size);
+            if !self.groups.borrow_mut().end_pass() {
+                break;
+            }
+        }
+        desired_size
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut SharedSizeScope,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        rect: Rect,
+    ) {
+        for child in control_context.get_children().into_iter() {
+            child.borrow_mut().set_rect(drawing_context, rect);
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &SharedSizeScope,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            for child in children.into_iter() {
+                let c = child.borrow();
+                let rect = c.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = c.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &SharedSizeScope,
+        control_context: &ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        let mut vec = Vec::new();
+        let mut overlay = Vec::new();
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let (mut vec2, mut overlay2) = child.borrow().to_primitives(drawing_context);
+            vec.append(&mut vec2);
+            overlay.append(&mut overlay2);
+        }
+
+        (vec, overlay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_has_size_of_largest_member() {
+        let mut groups = SharedSizeGroups::new();
+
+        groups.begin_pass();
+        assert_eq!(groups.update_size("A", 30.0f32), 30.0f32);
+        assert_eq!(groups.update_size("A", 50.0f32), 50.0f32);
+        assert_eq!(groups.update_size("B", 10.0f32), 10.0f32);
+        assert!(groups.end_pass());
+
+        // members measured before the largest one get its size in the next pass
+        groups.begin_pass();
+        assert_eq!(groups.update_size("A", 30.0f32), 50.0f32);
+        assert_eq!(groups.update_size("A", 50.0f32), 50.0f32);
+        assert_eq!(groups.update_size("B", 10.0f32), 10.0f32);
+        assert!(!groups.end_pass());
+    }
+
+    #[test]
+    fn group_shrinks_when_largest_member_shrinks() {
+        let mut groups = SharedSizeGroups::new();
+
+        groups.begin_pass();
+        groups.update_size("A", 50.0f32);
+        assert!(groups.end_pass());
+
+        groups.begin_pass();
+        assert_eq!(groups.update_size("A", 20.0f32), 50.0f32);
+        assert!(groups.end_pass());
+
+        groups.begin_pass();
+        assert_eq!(groups.update_size("A", 20.0f32), 20.0f32);
+        assert!(!groups.end_pass());
+    }
+
+    #[test]
+    fn group_without_members_is_removed() {
+        let mut groups = SharedSizeGroups::new();
+
+        groups.begin_pass();
+        groups.update_size("A", 50.0f32);
+        groups.update_size("B", 10.0f32);
+        groups.end_pass();
+
+        groups.begin_pass();
+        groups.update_size("B", 10.0f32);
+        assert!(!groups.end_pass());
+        assert!(!groups.groups.contains_key("A"));
+
+        // new member of the removed group does not get its old size
+        groups.begin_pass();
+        assert_eq!(groups.update_size("A", 20.0f32), 20.0f32);
+    }
+}
@@ -4,8 +4,8 @@ use std::rc::Rc;
 
 use crate::{
     Alignment, ControlContext, ControlEvent, ControlObject, DrawingContext, EventContext,
-    HorizontalAlignment, Orientation, Point, Rect, Size, Style, StyledControl, VerticalAlignment,
-    ViewContext,
+    HorizontalAlignment, Orientation, Point, Property, Rect, Size, Style, StyledControl,
+    VerticalAlignment, ViewContext,
 };
 use drawing::primitive::Primitive;
 use typed_builder::TypedBuilder;
@@ -26,11 +26,11 @@ impl StackPanel {
         context
             .attached_values
             .entry::<HorizontalAlignment>()
-            .or_insert(Alignment::Start);
+            .or_insert(Property::new(Alignment::Start));
         context
             .attached_values
             .entry::<VerticalAlignment>()
-            .or_insert(Alignment::Start);
+            .or_insert(Property::new(Alignment::Start));
 
         StyledControl::new(
             self,
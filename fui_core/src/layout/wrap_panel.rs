@@ -0,0 +1,333 @@
+use std::cell::RefCell;
+use std::f32;
+use std::rc::Rc;
+
+use crate::{
+    Alignment, ControlContext, ControlEvent, ControlObject, DrawingContext, EventContext,
+    HorizontalAlignment, Orientation, Point, Property, Rect, Size, Style, StyledControl,
+    VerticalAlignment, ViewContext,
+};
+use drawing::primitive::Primitive;
+use typed_builder::TypedBuilder;
+
+///
+/// Places children one after another and breaks the line
+/// when there is no more space in it.
+///
+/// With horizontal orientation the children flow from left to right and the lines
+/// go from top to bottom, with vertical orientation the children flow from top to bottom
+/// and the lines (columns) go from left to right.
+///
+/// Example:
+///
+/// ui! {
+///     WrapPanel {
+///         item_spacing: 4.0f32,
+///         line_spacing: 4.0f32,
+///         item_width: 64.0f32,
+///         item_height: 64.0f32,
+///         &vm.thumbnails,
+///     }
+/// }
+///
+#[derive(TypedBuilder)]
+pub struct WrapPanel {
+    #[builder(default = Orientation::Horizontal)]
+    pub orientation: Orientation,
+
+    /// Space between children in the line.
+    #[builder(default = 0.0f32)]
+    pub item_spacing: f32,
+
+    /// Space between the lines.
+    #[builder(default = 0.0f32)]
+    pub line_spacing: f32,
+
+    /// Width of every child (when not set, children have their measured widths).
+    #[builder(default = None)]
+    pub item_width: Option<f32>,
+
+    /// Height of every child (when not set, children have their measured heights).
+    #[builder(default = None)]
+    pub item_height: Option<f32>,
+
+    /// Alignment of the children in every line (along the orientation).
+    /// `Stretch` distributes the remaining space between the children.
+    #[builder(default = Alignment::Start)]
+    pub line_alignment: Alignment,
+}
+
+impl WrapPanel {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        mut context: ViewContext,
+    ) -> Rc<RefCell<dyn ControlObject>> {
+        // set default alignment to Start
+        context
+            .attached_values
+            .entry::<HorizontalAlignment>()
+            .or_insert(Property::new(Alignment::Start));
+        context
+            .attached_values
+            .entry::<VerticalAlignment>()
+            .or_insert(Property::new(Alignment::Start));
+
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultWrapPanelStyle::new(
+                    DefaultWrapPanelStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    ///
+    /// Returns (main, cross) sizes for the orientation.
+    ///
+    fn main_cross(&self, width: f32, height: f32) -> (f32, f32) {
+        match self.orientation {
+            Orientation::Horizontal => (width, height),
+            Orientation::Vertical => (height, width),
+        }
+    }
+
+    ///
+    /// Returns (width, height) from (main, cross) sizes.
+    ///
+    fn width_height(&self, main: f32, cross: f32) -> (f32, f32) {
+        // swapping is symmetric
+        self.main_cross(main, cross)
+    }
+
+    ///
+    /// Size of the child in the layout (uniform item size overrides the measured one).
+    ///
+    fn get_item_size(&self, child: &Rc<RefCell<dyn ControlObject>>) -> Option<Size> {
+        let rect = child.borrow().get_rect();
+
+        // invisible children take no space
+        if rect.width == 0.0f32 && rect.height == 0.0f32 {
+            return None;
+        }
+
+        Some(Size::new(
+            self.item_width.unwrap_or(rect.width),
+            self.item_height.unwrap_or(rect.height),
+        ))
+    }
+
+    ///
+    /// Breaks items (main, cross sizes) into lines that fit the available main size.
+    ///
+    fn calc_lines(&self, items: &[Option<(f32, f32)>], available_main: f32) -> Vec<Line> {
+        let mut lines = Vec::new();
+        let mut line = Line::new(0);
+
+        for (index, item) in items.iter().enumerate() {
+            if let Some((main, cross)) = *item {
+                if line.visible_count > 0 && line.main + self.item_spacing + main > available_main {
+                    lines.push(line);
+                    line = Line::new(index);
+                }
+
+                if line.visible_count > 0 {
+                    line.main += self.item_spacing;
+                }
+                line.main += main;
+                line.cross = line.cross.max(cross);
+                line.visible_count += 1;
+            }
+            line.end = index + 1;
+        }
+
+        if line.end > line.start {
+            lines.push(line);
+        }
+
+        lines
+    }
+}
+
+struct Line {
+    start: usize,
+    end: usize,
+    visible_count: usize,
+    main: f32,
+    cross: f32,
+}
+
+impl Line {
+    fn new(start: usize) -> Self {
+        Line {
+            start,
+            end: start,
+            visible_count: 0,
+            main: 0.0f32,
+            cross: 0.0f32,
+        }
+    }
+}
+
+//
+// Default WrapPanel Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultWrapPanelStyleParams {}
+
+pub struct DefaultWrapPanelStyle;
+
+impl DefaultWrapPanelStyle {
+    pub fn new(_params: DefaultWrapPanelStyleParams) -> Self {
+        DefaultWrapPanelStyle {}
+    }
+}
+
+impl Style<WrapPanel> for DefaultWrapPanelStyle {
+    fn setup(&mut self, _data: &mut WrapPanel, _control_context: &mut ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut WrapPanel,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut WrapPanel,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        size: Size,
+    ) -> Size {
+        let children = control_context.get_children();
+
+        let (available_main, _) = data.main_cross(size.width, size.height);
+        let (available_width, available_height) = data.width_height(available_main, f32::INFINITY);
+        let available_size = Size::new(
+            data.item_width.unwrap_or(available_width),
+            data.item_height.unwrap_or(available_height),
+        );
+
+        let mut items = Vec::new();
+        for child in children.into_iter() {
+            child.borrow_mut().measure(drawing_context, available_size);
+            items.push(
+                data.get_item_size(&child)
+                    .map(|size| data.main_cross(size.width, size.height)),
+            );
+        }
+
+        let lines = data.calc_lines(&items, available_main);
+
+        let main = lines.iter().map(|line| line.main).fold(0.0f32, f32::max);
+        let cross = lines.iter().map(|line| line.cross).sum::<f32>()
+            + data.line_spacing * lines.len().saturating_sub(1) as f32;
+
+        let (width, height) = data.width_height(main, cross);
+        Size::new(width, height)
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut WrapPanel,
+        control_context: &mut ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+        rect: Rect,
+    ) {
+        let children: Vec<_> = control_context.get_children().into_iter().collect();
+
+        let items: Vec<_> = children
+            .iter()
+            .map(|child| {
+                data.get_item_size(child)
+                    .map(|size| data.main_cross(size.width, size.height))
+            })
+            .collect();
+
+        let (available_main, _) = data.main_cross(rect.width, rect.height);
+        let (origin_main, origin_cross) = data.main_cross(rect.x, rect.y);
+
+        let mut cross_pos = origin_cross;
+        for line in data.calc_lines(&items, available_main) {
+            let free_space = (available_main - line.main).max(0.0f32);
+            let (mut main_pos, extra_spacing) = match data.line_alignment {
+                Alignment::Start => (origin_main, 0.0f32),
+                Alignment::Center => (origin_main + free_space / 2.0f32, 0.0f32),
+                Alignment::End => (origin_main + free_space, 0.0f32),
+                Alignment::Stretch if line.visible_count > 1 => {
+                    (origin_main, free_space / (line.visible_count - 1) as f32)
+                }
+                Alignment::Stretch => (origin_main, 0.0f32),
+            };
+
+            for index in line.start..line.end {
+                let mut child = children[index].borrow_mut();
+                match items[index] {
+                    Some((main, _)) => {
+                        let (x, y) = data.width_height(main_pos, cross_pos);
+                        let (width, height) = data.width_height(main, line.cross);
+                        child.set_rect(drawing_context, Rect::new(x, y, width, height));
+
+                        main_pos += main + data.item_spacing + extra_spacing;
+                    }
+                    None => {
+                        let (x, y) = data.width_height(main_pos, cross_pos);
+                        child.set_rect(drawing_context, Rect::new(x, y, 0.0f32, 0.0f32));
+                    }
+                }
+            }
+
+            cross_pos += line.cross + data.line_spacing;
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &WrapPanel,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            for child in children.into_iter() {
+                let c = child.borrow();
+                let rect = c.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = c.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &WrapPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        let mut vec = Vec::new();
+        let mut overlay = Vec::new();
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let (mut vec2, mut overlay2) = child.borrow().to_primitives(drawing_context);
+            vec.append(&mut vec2);
+            overlay.append(&mut overlay2);
+        }
+
+        (vec, overlay)
+    }
+}
@@ -11,6 +11,9 @@ pub use view::*;
 
 pub use crate::drawing::*;
 
+#[cfg(feature = "serde")]
+pub use serde;
+
 mod children;
 mod common;
 mod control;
@@ -22,3 +25,5 @@ mod services;
 mod style;
 mod task;
 mod view;
+
+pub mod testing;
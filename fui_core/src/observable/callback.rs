@@ -1,4 +1,5 @@
 use crate::spawn_local_and_forget;
+use crate::{DiagnosticsToken, ResourceKind};
 use std::cell::RefCell;
 use std::future::Future;
 use std::rc::Rc;
@@ -17,21 +18,33 @@ use std::rc::Rc;
 #[derive(Clone)]
 pub struct Callback<A> {
     callback: Rc<RefCell<Option<Box<dyn 'static + FnMut(A)>>>>,
+    _diagnostics: Rc<DiagnosticsToken>,
 }
 
 impl<A: 'static + Clone> Callback<A> {
+    #[track_caller]
     pub fn empty() -> Self {
         Callback {
             callback: Rc::new(RefCell::new(None)),
+            _diagnostics: Rc::new(DiagnosticsToken::new(ResourceKind::Callback)),
         }
     }
 
+    pub(crate) fn untracked() -> Self {
+        Callback {
+            callback: Rc::new(RefCell::new(None)),
+            _diagnostics: Rc::new(DiagnosticsToken::none()),
+        }
+    }
+
+    #[track_caller]
     pub fn new_sync<F: 'static + FnMut(A)>(f: F) -> Self {
         let mut callback = Callback::empty();
         callback.set_sync(f);
         callback
     }
 
+    #[track_caller]
     pub fn new_async<F, Fut>(f: F) -> Self
     where
         F: FnMut(A) -> Fut + 'static,
@@ -42,12 +55,14 @@ impl<A: 'static + Clone> Callback<A> {
         callback
     }
 
+    #[track_caller]
     pub fn new_vm<T: 'static, F: 'static + FnMut(&mut T, A)>(vm: &Rc<RefCell<T>>, f: F) -> Self {
         let mut callback = Callback::empty();
         callback.set_vm(vm, f);
         callback
     }
 
+    #[track_caller]
     pub fn new_vm_rc<T: 'static, F: 'static + FnMut(Rc<RefCell<T>>, A)>(
         vm: &Rc<RefCell<T>>,
         f: F,
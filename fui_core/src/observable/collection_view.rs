@@ -0,0 +1,389 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::observable::keyed_diff::diff_keyed;
+use crate::{Event, ObservableCollection, Property, Subscription, VecDiff};
+
+///
+/// Live view over the observable collection.
+///
+/// Shows only items matching the filter, ordered by the sort comparator
+/// and grouped by the group key (with a header item before every group).
+/// Every criterion can be changed at runtime or bound to a property.
+///
+/// Changes of the source collection and of the criteria are translated
+/// to minimal set of `VecDiff`s, so controls showing the view are updated
+/// incrementally (moved items are not recreated).
+///
+/// Example:
+///
+/// let mut view = CollectionView::new(&vm.people);
+/// view.bind_filter(&vm.search_text, |text: &String, person: &Rc<RefCell<PersonViewModel>>| {
+///     person.borrow().name.get().contains(text.as_str())
+/// });
+/// view.set_sort(|a, b| a.borrow().name.get().cmp(&b.borrow().name.get()));
+///
+/// ui! { Vertical { &view } }
+///
+pub struct CollectionView<T: 'static + Clone> {
+    inner: Rc<RefCell<CollectionViewInner<T>>>,
+    changed_event: Rc<RefCell<Event<VecDiff<T>>>>,
+    _source_changed_subscription: Option<Subscription>,
+    filter_subscription: Option<Subscription>,
+    sort_subscription: Option<Subscription>,
+    group_subscription: Option<Subscription>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Entry {
+    // source item by id
+    Item(usize),
+
+    // group header by group key
+    Header(String),
+}
+
+type Filter<T> = Box<dyn Fn(&T) -> bool>;
+type Sort<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+type GroupKey<T> = Box<dyn Fn(&T) -> String>;
+type GroupHeader<T> = Box<dyn Fn(&str) -> T>;
+
+struct Group<T> {
+    key: GroupKey<T>,
+    header: GroupHeader<T>,
+}
+
+struct CollectionViewInner<T> {
+    // source items with ids stable when the items are moved
+    source: Vec<(usize, T)>,
+    next_id: usize,
+
+    // ids of source items replaced since last refresh
+    replaced_ids: HashSet<usize>,
+
+    filter: Option<Filter<T>>,
+    sort: Option<Sort<T>>,
+    group: Option<Group<T>>,
+
+    // current content of the view
+    items: Vec<(Entry, T)>,
+}
+
+impl<T: 'static + Clone> CollectionView<T> {
+    pub fn new(source: &dyn ObservableCollection<T>) -> Self {
+        let mut inner = CollectionViewInner {
+            source: Vec::new(),
+            next_id: 0,
+            replaced_ids: HashSet::new(),
+            filter: None,
+            sort: None,
+            group: None,
+            items: Vec::new(),
+        };
+        for value in source.into_iter() {
+            let id = inner.new_id();
+            inner.source.push((id, value));
+        }
+        inner.refresh();
+
+        let inner = Rc::new(RefCell::new(inner));
+        let changed_event = Rc::new(RefCell::new(Event::new()));
+
+        let source_changed_subscription = source.on_changed(Box::new({
+            let inner = inner.clone();
+            let changed_event = changed_event.clone();
+            move |changed_args| {
+                let changes = {
+                    let mut inner = inner.borrow_mut();
+                    inner.apply_source_change(changed_args);
+                    inner.refresh()
+                };
+                CollectionView::emit_changes(&changed_event, changes);
+            }
+        }));
+
+        CollectionView {
+            inner,
+            changed_event,
+            _source_changed_subscription: source_changed_subscription,
+            filter_subscription: None,
+            sort_subscription: None,
+            group_subscription: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.borrow().items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().items.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.inner
+            .borrow()
+            .items
+            .get(index)
+            .map(|(_, value)| value.clone())
+    }
+
+    ///
+    /// Shows only items for which the predicate returns true.
+    ///
+    pub fn set_filter<F: 'static + Fn(&T) -> bool>(&mut self, f: F) {
+        self.filter_subscription = None;
+        self.update(move |inner| inner.filter = Some(Box::new(f)));
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter_subscription = None;
+        self.update(|inner| inner.filter = None);
+    }
+
+    ///
+    /// Filters items with predicate parametrized by the property value
+    /// (for example the text of the search box).
+    ///
+    pub fn bind_filter<P, F>(&mut self, property: &Property<P>, f: F)
+    where
+        P: 'static + Clone + PartialEq,
+        F: 'static + Fn(&P, &T) -> bool,
+    {
+        let f = Rc::new(f);
+        let set_filter = move |inner: &mut CollectionViewInner<T>, value: P| {
+            let f = f.clone();
+            inner.filter = Some(Box::new(move |item| f(&value, item)));
+        };
+        self.filter_subscription = Some(self.bind_criterion(property, set_filter));
+    }
+
+    ///
+    /// Orders items with the comparator.
+    ///
+    /// Sorting is stable, equal items are kept in the source order.
+    ///
+    pub fn set_sort<F: 'static + Fn(&T, &T) -> Ordering>(&mut self, f: F) {
+        self.sort_subscription = None;
+        self.update(move |inner| inner.sort = Some(Box::new(f)));
+    }
+
+    pub fn clear_sort(&mut self) {
+        self.sort_subscription = None;
+        self.update(|inner| inner.sort = None);
+    }
+
+    ///
+    /// Orders items with comparator parametrized by the property value
+    /// (for example the selected sort column).
+    ///
+    pub fn bind_sort<P, F>(&mut self, property: &Property<P>, f: F)
+    where
+        P: 'static + Clone + PartialEq,
+        F: 'static + Fn(&P, &T, &T) -> Ordering,
+    {
+        let f = Rc::new(f);
+        let set_sort = move |inner: &mut CollectionViewInner<T>, value: P| {
+            let f = f.clone();
+            inner.sort = Some(Box::new(move |a, b| f(&value, a, b)));
+        };
+        self.sort_subscription = Some(self.bind_criterion(property, set_sort));
+    }
+
+    ///
+    /// Groups items by the key.
+    ///
+    /// Groups are ordered by the key. Every non-empty group is preceded
+    /// by the header item created by the `header` function.
+    ///
+    pub fn set_group<FK, FH>(&mut self, key: FK, header: FH)
+    where
+        FK: 'static + Fn(&T) -> String,
+        FH: 'static + Fn(&str) -> T,
+    {
+        self.group_subscription = None;
+        self.update(move |inner| {
+            inner.group = Some(Group {
+                key: Box::new(key),
+                header: Box::new(header),
+            })
+        });
+    }
+
+    pub fn clear_group(&mut self) {
+        self.group_subscription = None;
+        self.update(|inner| inner.group = None);
+    }
+
+    ///
+    /// Groups items by the key parametrized by the property value.
+    ///
+    pub fn bind_group<P, FK, FH>(&mut self, property: &Property<P>, key: FK, header: FH)
+    where
+        P: 'static + Clone + PartialEq,
+        FK: 'static + Fn(&P, &T) -> String,
+        FH: 'static + Fn(&str) -> T,
+    {
+        let key = Rc::new(key);
+        let header = Rc::new(header);
+        let set_group = move |inner: &mut CollectionViewInner<T>, value: P| {
+            let key = key.clone();
+            let header = header.clone();
+            inner.group = Some(Group {
+                key: Box::new(move |item| key(&value, item)),
+                header: Box::new(move |group_key| header(group_key)),
+            });
+        };
+        self.group_subscription = Some(self.bind_criterion(property, set_group));
+    }
+
+    fn bind_criterion<P, F>(&mut self, property: &Property<P>, set_criterion: F) -> Subscription
+    where
+        P: 'static + Clone + PartialEq,
+        F: 'static + Fn(&mut CollectionViewInner<T>, P),
+    {
+        let set_criterion = Rc::new(set_criterion);
+
+        self.update({
+            let set_criterion = set_criterion.clone();
+            let value = property.get();
+            move |inner| set_criterion(inner, value)
+        });
+
+        let inner = self.inner.clone();
+        let changed_event = self.changed_event.clone();
+        property.on_changed(move |value| {
+            let changes = {
+                let mut inner = inner.borrow_mut();
+                set_criterion(&mut inner, value);
+                inner.refresh()
+            };
+            CollectionView::emit_changes(&changed_event, changes);
+        })
+    }
+
+    fn update<F: FnOnce(&mut CollectionViewInner<T>)>(&mut self, f: F) {
+        let changes = {
+            let mut inner = self.inner.borrow_mut();
+            f(&mut inner);
+            inner.refresh()
+        };
+        CollectionView::emit_changes(&self.changed_event, changes);
+    }
+
+    fn emit_changes(changed_event: &Rc<RefCell<Event<VecDiff<T>>>>, mut changes: Vec<VecDiff<T>>) {
+        match changes.len() {
+            0 => (),
+            1 => changed_event.borrow().emit(changes.remove(0)),
+            _ => changed_event.borrow().emit(VecDiff::Batch { changes }),
+        }
+    }
+}
+
+impl<T: 'static + Clone> CollectionViewInner<T> {
+    fn new_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn apply_source_change(&mut self, changed_args: VecDiff<T>) {
+        match changed_args {
+            VecDiff::Clear {} => self.source.clear(),
+            VecDiff::InsertAt { index, value } => {
+                let id = self.new_id();
+                self.source.insert(index, (id, value));
+            }
+            VecDiff::RemoveAt { index } => {
+                self.source.remove(index);
+            }
+            VecDiff::Replace { index, value } => {
+                let id = self.source[index].0;
+                self.source[index].1 = value;
+                self.replaced_ids.insert(id);
+            }
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => {
+                let item = self.source.remove(old_index);
+                self.source.insert(new_index, item);
+            }
+            VecDiff::Batch { changes } => {
+                for change in changes {
+                    self.apply_source_change(change);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Computes entries which should be shown by the view.
+    ///
+    fn calc_entries(&self) -> Vec<(Entry, T)> {
+        let mut items: Vec<(Option<String>, usize, &T)> = self
+            .source
+            .iter()
+            .filter(|(_, value)| match &self.filter {
+                Some(filter) => filter(value),
+                None => true,
+            })
+            .map(|(id, value)| {
+                let group_key = self.group.as_ref().map(|group| (group.key)(value));
+                (group_key, *id, value)
+            })
+            .collect();
+
+        // sort is stable, so items are in source order when comparator returns Equal
+        items.sort_by(|(key1, _, value1), (key2, _, value2)| {
+            key1.cmp(key2).then_with(|| {
+                self.sort
+                    .as_ref()
+                    .map_or(Ordering::Equal, |sort| sort(value1, value2))
+            })
+        });
+
+        let mut entries = Vec::with_capacity(items.len());
+        let mut last_group_key = None;
+        for (group_key, id, value) in items {
+            if let (Some(group), Some(group_key)) = (&self.group, &group_key) {
+                if last_group_key.as_ref() != Some(group_key) {
+                    entries.push((Entry::Header(group_key.clone()), (group.header)(group_key)));
+                    last_group_key = Some(group_key.clone());
+                }
+            }
+            entries.push((Entry::Item(id), value.clone()));
+        }
+        entries
+    }
+
+    ///
+    /// Updates the view and returns changes needed to get from the old to the new content.
+    ///
+    fn refresh(&mut self) -> Vec<VecDiff<T>> {
+        let new_entries = self.calc_entries();
+        let replaced_ids = std::mem::take(&mut self.replaced_ids);
+        diff_keyed(&mut self.items, new_entries, |entry, _, _| match entry {
+            Entry::Item(id) => replaced_ids.contains(id),
+            Entry::Header(_) => false,
+        })
+    }
+}
+
+impl<T: 'static + Clone> ObservableCollection<T> for CollectionView<T> {
+    fn len(&self) -> usize {
+        CollectionView::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        CollectionView::get(self, index)
+    }
+
+    fn on_changed(&self, f: Box<dyn FnMut(VecDiff<T>)>) -> Option<Subscription> {
+        Some(Subscription::EventSubscription(
+            self.changed_event.borrow_mut().subscribe(f),
+        ))
+    }
+}
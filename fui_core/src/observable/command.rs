@@ -0,0 +1,143 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::task::synchronous_executor::SynchronousExecutor;
+use crate::{spawn_local_and_forget, Property, ReadOnlyProperty};
+
+enum CommandAction {
+    Sync(Box<dyn FnMut()>),
+    Async(Box<dyn FnMut() -> Pin<Box<dyn Future<Output = ()>>>>),
+}
+
+///
+/// Action exposed by the view model together with its availability.
+///
+/// Controls executing the command (Button, MenuItem, keyboard shortcuts)
+/// are disabled when `can_execute` is false or when the async command is running.
+/// Async command cannot be executed again until the previous execution completes.
+///
+/// Like Callback, the action is queued and executed later on the same thread.
+///
+/// Example:
+///
+/// let mut save = Command::new_vm_async(&vm_rc, |vm| async move { vm.borrow().save().await; });
+/// save.can_execute.bind_read_only(&validation_group.is_valid());
+///
+/// ui! { Button { command: save.clone(), Text { text: "Save" } } }
+///
+#[derive(Clone)]
+pub struct Command {
+    pub can_execute: Property<bool>,
+    is_executing: Property<bool>,
+    is_enabled: ReadOnlyProperty<bool>,
+    is_async: bool,
+    action: Rc<RefCell<CommandAction>>,
+}
+
+impl Command {
+    fn new(action: CommandAction) -> Self {
+        let can_execute = Property::new(true);
+        let is_executing = Property::new(false);
+        let is_enabled = Property::computed2(
+            &can_execute,
+            &is_executing,
+            |can_execute, is_executing: bool| can_execute && !is_executing,
+        );
+        Command {
+            can_execute,
+            is_executing,
+            is_enabled,
+            is_async: matches!(action, CommandAction::Async(_)),
+            action: Rc::new(RefCell::new(action)),
+        }
+    }
+
+    pub fn new_sync<F: 'static + FnMut()>(f: F) -> Self {
+        Command::new(CommandAction::Sync(Box::new(f)))
+    }
+
+    pub fn new_async<F, Fut>(mut f: F) -> Self
+    where
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Command::new(CommandAction::Async(Box::new(move || Box::pin(f()))))
+    }
+
+    pub fn new_vm<T: 'static, F: 'static + FnMut(&mut T)>(vm: &Rc<RefCell<T>>, mut f: F) -> Self {
+        let vm_clone = vm.clone();
+        Command::new_sync(move || {
+            let mut vm = vm_clone.borrow_mut();
+            f(&mut vm);
+        })
+    }
+
+    pub fn new_vm_async<T, F, Fut>(vm: &Rc<RefCell<T>>, mut f: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(Rc<RefCell<T>>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let vm_clone = vm.clone();
+        Command::new_async(move || f(vm_clone.clone()))
+    }
+
+    ///
+    /// True when the async command is running.
+    ///
+    pub fn is_executing(&self) -> ReadOnlyProperty<bool> {
+        self.is_executing.read_only()
+    }
+
+    ///
+    /// True when the command can be executed now
+    /// (`can_execute` is true and the command is not running).
+    ///
+    pub fn is_enabled(&self) -> ReadOnlyProperty<bool> {
+        self.is_enabled.clone()
+    }
+
+    ///
+    /// Queues execution of the command.
+    ///
+    /// Returns false if the command is disabled.
+    ///
+    pub fn execute(&self) -> bool {
+        if !self.can_execute.get() || self.is_executing.get() {
+            return false;
+        }
+
+        let action = Rc::downgrade(&self.action);
+        if self.is_async {
+            // set before the task starts, so the command cannot be executed twice
+            let mut is_executing = self.is_executing.clone();
+            is_executing.set(true);
+
+            spawn_local_and_forget(async move {
+                let future = action
+                    .upgrade()
+                    .and_then(|action| match &mut *action.borrow_mut() {
+                        CommandAction::Async(f) => Some(f()),
+                        CommandAction::Sync(_) => None,
+                    });
+                if let Some(future) = future {
+                    future.await;
+                }
+                is_executing.set(false);
+            });
+        } else {
+            spawn_local_and_forget(async move {
+                if let Some(action) = action.upgrade() {
+                    if let CommandAction::Sync(f) = &mut *action.borrow_mut() {
+                        f();
+                    }
+                }
+            });
+        }
+
+        SynchronousExecutor::flush_if_enabled();
+        true
+    }
+}
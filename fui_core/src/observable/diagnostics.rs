@@ -0,0 +1,376 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::panic::Location;
+
+///
+/// Kind of the resource counted by the diagnostics.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    /// Subscription to an event (`EventSubscription`).
+    Subscription,
+
+    /// Callback (for example an event handler of a control).
+    Callback,
+
+    /// Task spawned with `spawn_local()` (bindings, `on_changed()` handlers).
+    Task,
+}
+
+///
+/// Resource which is still alive.
+///
+#[derive(Debug, Clone)]
+pub struct LiveResource {
+    /// Identifier increasing with every created resource.
+    pub id: usize,
+
+    pub kind: ResourceKind,
+
+    /// Where the resource was created.
+    pub location: &'static Location<'static>,
+
+    /// Type name of the control which was being created when the resource was created.
+    pub owner: Option<&'static str>,
+}
+
+impl fmt::Display for LiveResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} created at {}", self.kind, self.location)?;
+        if let Some(owner) = self.owner {
+            write!(f, " (owner: {})", owner)?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Resources still alive when their owning control was dropped.
+///
+#[derive(Debug, Clone)]
+pub struct LeakReport {
+    pub owner: &'static str,
+    pub resources: Vec<LiveResource>,
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} dropped with live resources:", self.owner)?;
+        for resource in &self.resources {
+            write!(f, "\n    {}", resource)?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Position in the sequence of created resources.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticsCheckpoint(usize);
+
+type LeakHook = Box<dyn Fn(&LeakReport)>;
+
+struct DiagnosticsState {
+    is_enabled: bool,
+    next_id: usize,
+    live: BTreeMap<usize, LiveResource>,
+
+    // controls being created (resources created meanwhile are owned by the last one)
+    owner_stack: Vec<(usize, &'static str)>,
+    next_owner_id: usize,
+
+    // owners entered by the `ui!` macro not yet taken by the created control
+    unclaimed_owner_ids: BTreeSet<usize>,
+
+    // owner ids of the live resources (by resource id)
+    owner_ids: BTreeMap<usize, usize>,
+
+    leak_hook: Option<std::rc::Rc<LeakHook>>,
+}
+
+thread_local! {
+    static DIAGNOSTICS: RefCell<DiagnosticsState> = const {
+        RefCell::new(DiagnosticsState {
+            is_enabled: false,
+            next_id: 0,
+            live: BTreeMap::new(),
+            owner_stack: Vec::new(),
+            next_owner_id: 0,
+            unclaimed_owner_ids: BTreeSet::new(),
+            owner_ids: BTreeMap::new(),
+            leak_hook: None,
+        })
+    };
+}
+
+///
+/// Debug facility counting live subscriptions, callbacks and binding tasks
+/// (per thread) together with the places where they were created.
+///
+/// Resources created while a control is being created (including the bindings
+/// of its properties in the `ui!` macro and the setup of its style) are owned by that control.
+/// When the control is dropped and some of its resources are still alive,
+/// the leak hook is called.
+///
+/// Counting is disabled by default. Only resources created when it is enabled are counted.
+///
+/// Example:
+///
+/// Diagnostics::set_enabled(true);
+/// let checkpoint = Diagnostics::checkpoint();
+///
+/// let window_content = MainViewModel::create_view(&vm);
+/// drop(window_content);
+///
+/// assert!(Diagnostics::live_since(checkpoint).is_empty());
+///
+pub struct Diagnostics;
+
+impl Diagnostics {
+    pub fn is_enabled() -> bool {
+        DIAGNOSTICS.with(|diagnostics| diagnostics.borrow().is_enabled)
+    }
+
+    pub fn set_enabled(is_enabled: bool) {
+        DIAGNOSTICS.with(|diagnostics| diagnostics.borrow_mut().is_enabled = is_enabled);
+    }
+
+    ///
+    /// Returns all counted resources which are still alive (in creation order).
+    ///
+    pub fn live_resources() -> Vec<LiveResource> {
+        DIAGNOSTICS.with(|diagnostics| diagnostics.borrow().live.values().cloned().collect())
+    }
+
+    pub fn live_count(kind: ResourceKind) -> usize {
+        DIAGNOSTICS.with(|diagnostics| {
+            diagnostics
+                .borrow()
+                .live
+                .values()
+                .filter(|resource| resource.kind == kind)
+                .count()
+        })
+    }
+
+    ///
+    /// Marks the current position, so resources created later can be queried.
+    ///
+    pub fn checkpoint() -> DiagnosticsCheckpoint {
+        DIAGNOSTICS.with(|diagnostics| DiagnosticsCheckpoint(diagnostics.borrow().next_id))
+    }
+
+    ///
+    /// Returns resources created after the checkpoint which are still alive.
+    ///
+    pub fn live_since(checkpoint: DiagnosticsCheckpoint) -> Vec<LiveResource> {
+        DIAGNOSTICS.with(|diagnostics| {
+            diagnostics
+                .borrow()
+                .live
+                .range(checkpoint.0..)
+                .map(|(_, resource)| resource.clone())
+                .collect()
+        })
+    }
+
+    ///
+    /// Sets handler called when a control is dropped while its resources are still alive.
+    ///
+    /// Nothing is reported when no handler is set.
+    ///
+    /// Example:
+    ///
+    /// Diagnostics::set_leak_hook(Some(|report: &LeakReport| eprintln!("Warning: {}", report)));
+    ///
+    pub fn set_leak_hook<F: 'static + Fn(&LeakReport)>(f: Option<F>) {
+        let hook = f.map(|f| std::rc::Rc::new(Box::new(f) as LeakHook));
+        DIAGNOSTICS.with(|diagnostics| diagnostics.borrow_mut().leak_hook = hook);
+    }
+
+    ///
+    /// Resources created until the returned scope is dropped are owned
+    /// by the control created next (or by its nested controls).
+    ///
+    /// Used by the `ui!` macro, so the bindings of the control's properties
+    /// (created before the control) are owned by the control.
+    ///
+    #[doc(hidden)]
+    pub fn control_scope<D>() -> DiagnosticsControlScope {
+        let id = DIAGNOSTICS.with(|diagnostics| {
+            let mut diagnostics = diagnostics.borrow_mut();
+            if !diagnostics.is_enabled {
+                return None;
+            }
+
+            let id = diagnostics.next_owner_id;
+            diagnostics.next_owner_id += 1;
+            diagnostics
+                .owner_stack
+                .push((id, std::any::type_name::<D>()));
+            diagnostics.unclaimed_owner_ids.insert(id);
+            Some(id)
+        });
+        DiagnosticsControlScope { id }
+    }
+}
+
+///
+/// Scope of the control created by the `ui!` macro.
+///
+#[doc(hidden)]
+pub struct DiagnosticsControlScope {
+    id: Option<usize>,
+}
+
+impl Drop for DiagnosticsControlScope {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            let _ = DIAGNOSTICS.try_with(|diagnostics| {
+                let mut diagnostics = diagnostics.borrow_mut();
+                diagnostics.owner_stack.pop();
+                diagnostics.unclaimed_owner_ids.remove(&id);
+            });
+        }
+    }
+}
+
+///
+/// Registration of the counted resource. The resource is removed when the token is dropped.
+///
+pub(crate) struct DiagnosticsToken {
+    id: Option<usize>,
+}
+
+impl DiagnosticsToken {
+    #[track_caller]
+    pub fn new(kind: ResourceKind) -> Self {
+        let location = Location::caller();
+        let id = DIAGNOSTICS.with(|diagnostics| {
+            let mut diagnostics = diagnostics.borrow_mut();
+            if !diagnostics.is_enabled {
+                return None;
+            }
+
+            let id = diagnostics.next_id;
+            diagnostics.next_id += 1;
+
+            let owner = diagnostics.owner_stack.last().cloned();
+            if let Some((owner_id, _)) = owner {
+                diagnostics.owner_ids.insert(id, owner_id);
+            }
+            diagnostics.live.insert(
+                id,
+                LiveResource {
+                    id,
+                    kind,
+                    location,
+                    owner: owner.map(|(_, owner_name)| owner_name),
+                },
+            );
+            Some(id)
+        });
+        DiagnosticsToken { id }
+    }
+
+    pub fn none() -> Self {
+        DiagnosticsToken { id: None }
+    }
+}
+
+impl Drop for DiagnosticsToken {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            let _ = DIAGNOSTICS.try_with(|diagnostics| {
+                let mut diagnostics = diagnostics.borrow_mut();
+                diagnostics.live.remove(&id);
+                diagnostics.owner_ids.remove(&id);
+            });
+        }
+    }
+}
+
+///
+/// Control owning resources for the diagnostics.
+///
+/// Should be dropped after all the resources of the control are dropped.
+///
+pub(crate) struct DiagnosticsOwner {
+    id: Option<(usize, &'static str)>,
+}
+
+impl DiagnosticsOwner {
+    pub fn new<D>() -> Self {
+        let id = DIAGNOSTICS.with(|diagnostics| {
+            let mut diagnostics = diagnostics.borrow_mut();
+            if !diagnostics.is_enabled {
+                return None;
+            }
+
+            // take the owner entered by the `ui!` macro for this control
+            if let Some((id, name)) = diagnostics.owner_stack.last().cloned() {
+                if diagnostics.unclaimed_owner_ids.remove(&id) {
+                    return Some((id, name));
+                }
+            }
+
+            let id = diagnostics.next_owner_id;
+            diagnostics.next_owner_id += 1;
+            Some((id, std::any::type_name::<D>()))
+        });
+        DiagnosticsOwner { id }
+    }
+
+    ///
+    /// Resources are owned by this owner until the returned scope is dropped.
+    ///
+    pub fn enter(&self) -> DiagnosticsOwnerScope {
+        if let Some(id) = self.id {
+            DIAGNOSTICS.with(|diagnostics| diagnostics.borrow_mut().owner_stack.push(id));
+        }
+        DiagnosticsOwnerScope {
+            is_entered: self.id.is_some(),
+        }
+    }
+}
+
+impl Drop for DiagnosticsOwner {
+    fn drop(&mut self) {
+        let (owner_id, owner) = match self.id {
+            Some(id) => id,
+            None => return,
+        };
+
+        let report = DIAGNOSTICS.try_with(|diagnostics| {
+            let diagnostics = diagnostics.borrow();
+            let resources: Vec<LiveResource> = diagnostics
+                .owner_ids
+                .iter()
+                .filter(|(_, id)| **id == owner_id)
+                .map(|(resource_id, _)| diagnostics.live[resource_id].clone())
+                .collect();
+            (resources, diagnostics.leak_hook.clone())
+        });
+
+        // hook is called when the diagnostics is not borrowed
+        if let Ok((resources, Some(leak_hook))) = report {
+            if !resources.is_empty() {
+                leak_hook(&LeakReport { owner, resources });
+            }
+        }
+    }
+}
+
+pub(crate) struct DiagnosticsOwnerScope {
+    is_entered: bool,
+}
+
+impl Drop for DiagnosticsOwnerScope {
+    fn drop(&mut self) {
+        if self.is_entered {
+            let _ = DIAGNOSTICS.try_with(|diagnostics| diagnostics.borrow_mut().owner_stack.pop());
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::rc::Rc;
 use std::rc::Weak;
 
 use crate::Callback;
+use crate::{DiagnosticsToken, ResourceKind};
 
 ///
 /// EventSubscription is an owner of the callback (handler).
@@ -15,16 +16,20 @@ use crate::Callback;
 ///
 pub struct EventSubscription {
     _callbacks: Vec<Rc<dyn CallbackObject>>,
+    _diagnostics: Vec<DiagnosticsToken>,
 }
 
 impl EventSubscription {
     pub fn from_many(event_subscriptions: Vec<EventSubscription>) -> Self {
         let mut callbacks = Vec::new();
+        let mut diagnostics = Vec::new();
         for mut subscription in event_subscriptions.into_iter() {
             callbacks.append(&mut subscription._callbacks);
+            diagnostics.append(&mut subscription._diagnostics);
         }
         EventSubscription {
             _callbacks: callbacks,
+            _diagnostics: diagnostics,
         }
     }
 }
@@ -47,8 +52,10 @@ impl<A: 'static + Clone> Event<A> {
         }
     }
 
+    #[track_caller]
     pub fn subscribe<F: 'static + FnMut(A)>(&mut self, f: F) -> EventSubscription {
-        let mut callback = Callback::<A>::empty();
+        // counted as the subscription, not as the callback
+        let mut callback = Callback::<A>::untracked();
         callback.set_sync(f);
         let rc_callback = Rc::new(callback);
         let weak_callback = Rc::downgrade(&rc_callback);
@@ -56,6 +63,7 @@ impl<A: 'static + Clone> Event<A> {
 
         EventSubscription {
             _callbacks: vec![rc_callback],
+            _diagnostics: vec![DiagnosticsToken::new(ResourceKind::Subscription)],
         }
     }
 
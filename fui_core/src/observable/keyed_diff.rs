@@ -0,0 +1,362 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::VecDiff;
+
+///
+/// Changes `items` to `new_items` and returns the changes needed to do it.
+///
+/// Items are matched by their keys. Items which keep their relative order
+/// (the longest increasing subsequence) are not touched, other items
+/// are removed, inserted or moved exactly once. Matched items for which
+/// `is_replaced` returns true are replaced with the new value.
+///
+/// Keys have to be unique. When they are not, all the items are cleared and inserted again.
+///
+pub(crate) fn diff_keyed<K, T, F>(
+    items: &mut Vec<(K, T)>,
+    new_items: Vec<(K, T)>,
+    is_replaced: F,
+) -> Vec<VecDiff<T>>
+where
+    K: Clone + Eq + Hash,
+    T: Clone,
+    F: Fn(&K, &T, &T) -> bool,
+{
+    let mut changes = Vec::new();
+
+    let new_positions: HashMap<&K, usize> = new_items
+        .iter()
+        .enumerate()
+        .map(|(index, (key, _))| (key, index))
+        .collect();
+    let old_keys: HashSet<&K> = items.iter().map(|(key, _)| key).collect();
+    if new_positions.len() != new_items.len() || old_keys.len() != items.len() {
+        if !items.is_empty() {
+            changes.push(VecDiff::Clear {});
+        }
+        for (index, (_, value)) in new_items.iter().enumerate() {
+            changes.push(VecDiff::InsertAt {
+                index,
+                value: value.clone(),
+            });
+        }
+        *items = new_items;
+        return changes;
+    }
+
+    // remove items not present in the new content
+    // (from the end, so the indexes of the next removals stay valid)
+    for index in (0..items.len()).rev() {
+        if !new_positions.contains_key(&items[index].0) {
+            changes.push(VecDiff::RemoveAt { index });
+        }
+    }
+    items.retain(|(key, _)| new_positions.contains_key(key));
+
+    {
+        // items which stay in place
+        let kept_new_positions: Vec<usize> =
+            items.iter().map(|(key, _)| new_positions[key]).collect();
+        let stable_positions: HashSet<usize> = longest_increasing_subsequence(&kept_new_positions)
+            .into_iter()
+            .collect();
+
+        // Every item in the list has a slot. Slots are ordered by the new position,
+        // not yet moved items are placed just before the next stable item
+        // (the one following them in the old order), so the index of an item
+        // is the number of occupied slots before its slot.
+        let mut slot_keys: Vec<(usize, usize, usize)> = (0..new_items.len())
+            .map(|new_index| (new_index, 1, 0))
+            .collect();
+        let mut old_slots: HashMap<usize, usize> = HashMap::new();
+        let mut next_stable_position = new_items.len();
+        for (kept_index, new_position) in kept_new_positions.iter().enumerate().rev() {
+            if stable_positions.contains(new_position) {
+                next_stable_position = *new_position;
+            } else {
+                old_slots.insert(kept_index, slot_keys.len());
+                slot_keys.push((next_stable_position, 0, kept_index));
+            }
+        }
+
+        let mut sorted_slots: Vec<usize> = (0..slot_keys.len()).collect();
+        sorted_slots.sort_by_key(|slot| slot_keys[*slot]);
+        let mut ranks = vec![0; slot_keys.len()];
+        for (rank, slot) in sorted_slots.into_iter().enumerate() {
+            ranks[slot] = rank;
+        }
+
+        let mut occupied = FenwickTree::new(slot_keys.len());
+        for new_position in &stable_positions {
+            occupied.add(ranks[*new_position], 1);
+        }
+        for slot in old_slots.values() {
+            occupied.add(ranks[*slot], 1);
+        }
+
+        // move and insert items in the order of the new content
+        let kept_indexes: HashMap<&K, usize> = items
+            .iter()
+            .enumerate()
+            .map(|(index, (key, _))| (key, index))
+            .collect();
+        for (new_index, (key, value)) in new_items.iter().enumerate() {
+            if stable_positions.contains(&new_index) {
+                continue;
+            }
+
+            match kept_indexes.get(key) {
+                Some(kept_index) => {
+                    let old_rank = ranks[old_slots[kept_index]];
+                    let old_index = occupied.prefix_sum(old_rank);
+                    occupied.add(old_rank, -1);
+                    let new_index = occupied.prefix_sum(ranks[new_index]);
+                    if old_index != new_index {
+                        changes.push(VecDiff::Move {
+                            old_index,
+                            new_index,
+                        });
+                    }
+                }
+                None => {
+                    changes.push(VecDiff::InsertAt {
+                        index: occupied.prefix_sum(ranks[new_index]),
+                        value: value.clone(),
+                    });
+                }
+            }
+            occupied.add(ranks[new_index], 1);
+        }
+    }
+
+    // replaced items (new items are inserted with the new value)
+    let mut old_values: HashMap<K, T> = items.drain(..).collect();
+    for (index, (key, value)) in new_items.into_iter().enumerate() {
+        match old_values.remove(&key) {
+            Some(old_value) if !is_replaced(&key, &old_value, &value) => {
+                items.push((key, old_value));
+            }
+            Some(_) => {
+                changes.push(VecDiff::Replace {
+                    index,
+                    value: value.clone(),
+                });
+                items.push((key, value));
+            }
+            None => items.push((key, value)),
+        }
+    }
+
+    changes
+}
+
+///
+/// Counts occupied positions, so the number of occupied positions
+/// before any position can be found in O(log n).
+///
+struct FenwickTree {
+    tree: Vec<isize>,
+}
+
+impl FenwickTree {
+    fn new(len: usize) -> Self {
+        FenwickTree {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    fn add(&mut self, position: usize, delta: isize) {
+        let mut i = position + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    ///
+    /// Sum of the values before the position.
+    ///
+    fn prefix_sum(&self, position: usize) -> usize {
+        let mut sum = 0;
+        let mut i = position;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum as usize
+    }
+}
+
+///
+/// Returns the longest strictly increasing subsequence of the values.
+///
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+    // tails[i] - index of the smallest tail of increasing subsequence of length i + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors: Vec<Option<usize>> = vec![None; values.len()];
+
+    for (index, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|&tail| values[tail] < *value);
+        if length > 0 {
+            predecessors[index] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+
+    let mut result = Vec::with_capacity(tails.len());
+    let mut index = tails.last().copied();
+    while let Some(i) = index {
+        result.push(values[i]);
+        index = predecessors[i];
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(keys: &str) -> Vec<(char, String)> {
+        keys.chars().map(|key| (key, key.to_string())).collect()
+    }
+
+    ///
+    /// Diffs the lists, checks the changes turn the old list into the new one
+    /// and returns the changes.
+    ///
+    fn diff(old_keys: &str, new_keys: &str) -> Vec<VecDiff<String>> {
+        let mut items = entries(old_keys);
+        let changes = diff_keyed(&mut items, entries(new_keys), |_, old, new| old != new);
+
+        let mut replayed: Vec<String> = entries(old_keys).into_iter().map(|(_, v)| v).collect();
+        for change in changes.clone() {
+            change.apply_to(&mut replayed);
+        }
+        let expected: Vec<String> = entries(new_keys).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(replayed, expected, "{} -> {}", old_keys, new_keys);
+        assert_eq!(items, entries(new_keys));
+
+        changes
+    }
+
+    fn count(changes: &[VecDiff<String>]) -> (usize, usize, usize) {
+        let moves = changes
+            .iter()
+            .filter(|change| matches!(change, VecDiff::Move { .. }))
+            .count();
+        let inserts = changes
+            .iter()
+            .filter(|change| matches!(change, VecDiff::InsertAt { .. }))
+            .count();
+        let removes = changes
+            .iter()
+            .filter(|change| matches!(change, VecDiff::RemoveAt { .. }))
+            .count();
+        (moves, inserts, removes)
+    }
+
+    #[test]
+    fn same_content_has_no_changes() {
+        assert!(diff("abcde", "abcde").is_empty());
+    }
+
+    #[test]
+    fn reorder_is_moves_only() {
+        // one item moved to the front
+        assert_eq!(count(&diff("abcde", "eabcd")), (1, 0, 0));
+
+        // one item moved to the end
+        assert_eq!(count(&diff("abcde", "bcdea")), (1, 0, 0));
+
+        // reversed (longest stable subsequence has one item)
+        assert_eq!(count(&diff("abcde", "edcba")), (4, 0, 0));
+
+        // two swaps
+        assert_eq!(count(&diff("abcdef", "badcfe")), (3, 0, 0));
+    }
+
+    #[test]
+    fn insert_and_remove_in_the_middle() {
+        let changes = diff("abcde", "abxde");
+        assert_eq!(count(&changes), (0, 1, 1));
+
+        assert_eq!(count(&diff("abcde", "abde")), (0, 0, 1));
+        assert_eq!(count(&diff("abde", "abcde")), (0, 1, 0));
+        assert_eq!(count(&diff("abcdef", "axdcyf")), (1, 2, 2));
+    }
+
+    #[test]
+    fn empty_lists() {
+        assert_eq!(count(&diff("", "abc")), (0, 3, 0));
+        assert_eq!(count(&diff("abc", "")), (0, 0, 3));
+        assert!(diff("", "").is_empty());
+    }
+
+    #[test]
+    fn duplicate_keys_replace_everything() {
+        let changes = diff("abca", "abc");
+        assert!(matches!(changes[0], VecDiff::Clear {}));
+        assert_eq!(count(&changes), (0, 3, 0));
+
+        let changes = diff("abc", "abba");
+        assert!(matches!(changes[0], VecDiff::Clear {}));
+        assert_eq!(count(&changes), (0, 4, 0));
+    }
+
+    #[test]
+    fn changed_values_are_replaced() {
+        let mut items = entries("abc");
+        let mut new_items = entries("cab");
+        new_items[1].1 = "A".to_string();
+
+        let changes = diff_keyed(&mut items, new_items.clone(), |_, old, new| old != new);
+        assert_eq!(items, new_items);
+        assert!(matches!(
+            changes.last(),
+            Some(VecDiff::Replace { index: 1, value }) if value == "A"
+        ));
+    }
+
+    #[test]
+    fn random_lists_are_minimal() {
+        // simple linear congruential generator, so the test is deterministic
+        let mut seed = 12345u64;
+        let mut random = move |max: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) as usize) % max
+        };
+
+        let alphabet: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        for _ in 0..500 {
+            let mut old_keys = alphabet.clone();
+            let mut new_keys = alphabet.clone();
+            for keys in [&mut old_keys, &mut new_keys] {
+                for i in (1..keys.len()).rev() {
+                    keys.swap(i, random(i + 1));
+                }
+                keys.truncate(random(keys.len() + 1));
+            }
+            let old_keys: String = old_keys.into_iter().collect();
+            let new_keys: String = new_keys.into_iter().collect();
+
+            let (moves, inserts, removes) = count(&diff(&old_keys, &new_keys));
+
+            let kept_positions: Vec<usize> = old_keys
+                .chars()
+                .filter_map(|key| new_keys.chars().position(|k| k == key))
+                .collect();
+            let stable = longest_increasing_subsequence(&kept_positions).len();
+            assert_eq!(moves, kept_positions.len() - stable);
+            assert_eq!(inserts, new_keys.len() - kept_positions.len());
+            assert_eq!(removes, old_keys.len() - kept_positions.len());
+        }
+    }
+}
@@ -1,12 +1,24 @@
 mod callback;
 pub use callback::*;
 
+mod diagnostics;
+pub use diagnostics::*;
+
 mod event;
 pub use event::*;
 
+mod keyed_diff;
+
+#[cfg(feature = "serde")]
+mod persistent_state;
+#[cfg(feature = "serde")]
+pub use persistent_state::*;
+
 mod property;
 pub use property::*;
 
+mod property_operators;
+
 mod observable_collection;
 pub use observable_collection::*;
 
@@ -19,8 +31,44 @@ pub use observable_collection_flat_map::*;
 mod observable_composite;
 pub use observable_composite::*;
 
+mod observable_tree;
+pub use observable_tree::*;
+
+mod observable_tree_rows;
+pub use observable_tree_rows::*;
+
+mod observable_map;
+pub use observable_map::*;
+
+mod observable_map_values;
+pub use observable_map_values::*;
+
 mod observable_vec;
 pub use observable_vec::*;
 
+mod read_only_property;
+pub use read_only_property::*;
+
+mod remote;
+pub use remote::*;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+mod streams;
+pub use streams::*;
+
 mod subscription;
 pub use subscription::*;
+
+mod undo_manager;
+pub use undo_manager::*;
+
+mod validation;
+pub use validation::*;
+
+mod command;
+pub use command::*;
+
+mod collection_view;
+pub use collection_view::*;
@@ -1,5 +1,10 @@
 use crate::Subscription;
 
+///
+/// Describes change of the observable collection.
+///
+/// `Batch` notifies about many changes (applied one after another) with a single event.
+///
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VecDiff<A> {
     Clear {},
@@ -7,6 +12,76 @@ pub enum VecDiff<A> {
     InsertAt { index: usize, value: A },
 
     RemoveAt { index: usize },
+
+    Replace { index: usize, value: A },
+
+    Move { old_index: usize, new_index: usize },
+
+    Batch { changes: Vec<VecDiff<A>> },
+}
+
+impl<A> VecDiff<A> {
+    ///
+    /// Converts values carried by the diff.
+    /// Indexes are left untouched.
+    ///
+    pub fn map<B, F: FnMut(A) -> B>(self, mut f: F) -> VecDiff<B> {
+        self.map_dyn(&mut f)
+    }
+
+    fn map_dyn<B>(self, f: &mut dyn FnMut(A) -> B) -> VecDiff<B> {
+        match self {
+            VecDiff::Clear {} => VecDiff::Clear {},
+            VecDiff::InsertAt { index, value } => VecDiff::InsertAt {
+                index,
+                value: f(value),
+            },
+            VecDiff::RemoveAt { index } => VecDiff::RemoveAt { index },
+            VecDiff::Replace { index, value } => VecDiff::Replace {
+                index,
+                value: f(value),
+            },
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => VecDiff::Move {
+                old_index,
+                new_index,
+            },
+            VecDiff::Batch { changes } => VecDiff::Batch {
+                changes: changes
+                    .into_iter()
+                    .map(|change| change.map_dyn(f))
+                    .collect(),
+            },
+        }
+    }
+
+    ///
+    /// Applies the diff to the vector.
+    ///
+    pub fn apply_to(self, vec: &mut Vec<A>) {
+        match self {
+            VecDiff::Clear {} => vec.clear(),
+            VecDiff::InsertAt { index, value } => vec.insert(index, value),
+            VecDiff::RemoveAt { index } => {
+                vec.remove(index);
+            }
+            VecDiff::Replace { index, value } => vec[index] = value,
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => {
+                let value = vec.remove(old_index);
+                vec.insert(new_index, value);
+            }
+            VecDiff::Batch { changes } => {
+                for change in changes {
+                    change.apply_to(vec);
+                }
+            }
+        }
+    }
 }
 
 pub trait ObservableCollection<T: 'static + Clone> {
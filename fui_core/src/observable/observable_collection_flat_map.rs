@@ -118,112 +118,227 @@ where
             let sub_collection_data_rc = sub_collection_data_rc.clone();
             let sub_collection_data_indexes_rc = sub_collection_data_indexes_rc.clone();
             let changed_event_rc = changed_event_rc.clone();
-            move |changed_args| match changed_args {
-                VecDiff::Clear {} => {
-                    // we are removing all sub-collections
-
-                    items_rc.borrow_mut().clear();
-                    sub_collection_data_rc.borrow_mut().clear();
-                    sub_collection_data_indexes_rc.borrow_mut().clear();
-                    changed_event_rc.borrow().emit(VecDiff::Clear {});
-                }
-
-                VecDiff::InsertAt { index, value } => {
-                    // we are inserting new sub-collection
-
-                    let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
-                    let new_pos = if index > 0 {
-                        sub_collection_data[index - 1].pos + sub_collection_data[index - 1].size
-                    } else {
-                        0
-                    };
-
-                    // update sub_collection_data_indexes
-                    let sub_collection_data_index = Rc::new(Cell::new(index as i32));
-                    let mut sub_collection_data_indexes =
-                        sub_collection_data_indexes_rc.borrow_mut();
-                    sub_collection_data_indexes.insert(index, sub_collection_data_index.clone());
-                    for i in index + 1..sub_collection_data_indexes.len() {
-                        sub_collection_data_indexes[i].set(i as i32)
-                    }
+            move |changed_args: VecDiff<TSrc>| {
+                let is_batch = matches!(changed_args, VecDiff::Batch { .. });
+                let mut dst_changes = Vec::new();
+                handle_source_change(
+                    changed_args,
+                    &mut f,
+                    &items_rc,
+                    &sub_collection_data_rc,
+                    &sub_collection_data_indexes_rc,
+                    &changed_event_rc,
+                    &mut dst_changes,
+                );
+                emit_changes(&changed_event_rc.borrow(), dst_changes, is_batch);
+            }
+        });
+        let event_subscription = self.on_changed(handler);
 
-                    // get new items
-                    let new_items = f(&value);
-
-                    // subscribe to changes
-                    let subscription = subscribe_to_subcollection(
-                        &new_items,
-                        items_rc.clone(),
-                        sub_collection_data_rc.clone(),
-                        sub_collection_data_index,
-                        changed_event_rc.clone(),
-                    );
-
-                    // update sub_collection_data
-                    let size = new_items.len() as i32;
-                    sub_collection_data.insert(
-                        index,
-                        SubCollectionData {
-                            pos: new_pos,
-                            size,
-                            _items_changed_event_subscription: subscription,
-                        },
-                    );
-                    for index in index + 1..sub_collection_data.len() {
-                        sub_collection_data[index].pos += size;
-                    }
+        ObservableCollectionFlatMap {
+            items: items_rc,
+            _sub_collection_data: sub_collection_data_rc,
+            _sub_collection_data_indexes: sub_collection_data_indexes_rc,
+            changed_event: changed_event_rc,
+            _items_changed_event_subscription: event_subscription,
+        }
+    }
+}
 
-                    // insert new items
-                    for (index, new_item) in new_items.into_iter().enumerate() {
-                        items_rc
-                            .borrow_mut()
-                            .insert(new_pos as usize + index, new_item.clone());
-                        changed_event_rc.borrow().emit(VecDiff::InsertAt {
-                            index: new_pos as usize + index,
-                            value: new_item,
-                        });
-                    }
-                }
+///
+/// Handles change of the source collection (insertion, removal etc. of the whole sub-collections).
+/// Changes of the output collection are appended to `dst_changes`.
+///
+fn handle_source_change<TSrc, TDst, TDstColl, F>(
+    changed_args: VecDiff<TSrc>,
+    f: &mut F,
+    items_rc: &Rc<RefCell<Vec<TDst>>>,
+    sub_collection_data_rc: &Rc<RefCell<Vec<SubCollectionData>>>,
+    sub_collection_data_indexes_rc: &Rc<RefCell<Vec<Rc<Cell<i32>>>>>,
+    changed_event_rc: &Rc<RefCell<Event<VecDiff<TDst>>>>,
+    dst_changes: &mut Vec<VecDiff<TDst>>,
+) where
+    TSrc: Clone + 'static,
+    TDst: Clone + 'static,
+    TDstColl: ObservableCollection<TDst> + IntoIterator<Item = TDst>,
+    F: FnMut(&TSrc) -> TDstColl,
+{
+    match changed_args {
+        VecDiff::Clear {} => {
+            // we are removing all sub-collections
+
+            items_rc.borrow_mut().clear();
+            sub_collection_data_rc.borrow_mut().clear();
+            sub_collection_data_indexes_rc.borrow_mut().clear();
+            dst_changes.push(VecDiff::Clear {});
+        }
 
-                VecDiff::RemoveAt { index } => {
-                    // we are removing a single sub-collection
+        VecDiff::InsertAt { index, value } => {
+            // we are inserting new sub-collection
 
-                    let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+            let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+            let new_pos = if index > 0 {
+                sub_collection_data[index - 1].pos + sub_collection_data[index - 1].size
+            } else {
+                0
+            };
 
-                    // remove subscription
-                    let removed_data = sub_collection_data.remove(index);
+            // update sub_collection_data_indexes
+            let sub_collection_data_index = Rc::new(Cell::new(index as i32));
+            let mut sub_collection_data_indexes = sub_collection_data_indexes_rc.borrow_mut();
+            sub_collection_data_indexes.insert(index, sub_collection_data_index.clone());
+            for i in index + 1..sub_collection_data_indexes.len() {
+                sub_collection_data_indexes[i].set(i as i32)
+            }
 
-                    // update sub_collection_data_indexes
-                    let mut sub_collection_data_indexes =
-                        sub_collection_data_indexes_rc.borrow_mut();
-                    sub_collection_data_indexes.remove(index);
-                    for i in index..sub_collection_data_indexes.len() {
-                        sub_collection_data_indexes[i].set(i as i32)
-                    }
+            // get new items
+            let new_items = f(&value);
 
-                    // fix indexes of other sub-collections
-                    for i in index..sub_collection_data.len() {
-                        sub_collection_data[i].pos -= removed_data.size;
-                    }
+            // subscribe to changes
+            let subscription = subscribe_to_subcollection(
+                &new_items,
+                items_rc.clone(),
+                sub_collection_data_rc.clone(),
+                sub_collection_data_index,
+                changed_event_rc.clone(),
+            );
+
+            // update sub_collection_data
+            let size = new_items.len() as i32;
+            sub_collection_data.insert(
+                index,
+                SubCollectionData {
+                    pos: new_pos,
+                    size,
+                    _items_changed_event_subscription: subscription,
+                },
+            );
+            for index in index + 1..sub_collection_data.len() {
+                sub_collection_data[index].pos += size;
+            }
+
+            // insert new items
+            let mut items = items_rc.borrow_mut();
+            for (index, new_item) in new_items.into_iter().enumerate() {
+                items.insert(new_pos as usize + index, new_item.clone());
+                dst_changes.push(VecDiff::InsertAt {
+                    index: new_pos as usize + index,
+                    value: new_item,
+                });
+            }
+        }
+
+        VecDiff::RemoveAt { index } => {
+            // we are removing a single sub-collection
+
+            let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+
+            // remove subscription
+            let removed_data = sub_collection_data.remove(index);
+
+            // update sub_collection_data_indexes
+            let mut sub_collection_data_indexes = sub_collection_data_indexes_rc.borrow_mut();
+            sub_collection_data_indexes.remove(index);
+            for i in index..sub_collection_data_indexes.len() {
+                sub_collection_data_indexes[i].set(i as i32)
+            }
+
+            // fix indexes of other sub-collections
+            for i in index..sub_collection_data.len() {
+                sub_collection_data[i].pos -= removed_data.size;
+            }
+
+            // remove elements
+            let mut items = items_rc.borrow_mut();
+            for i in (removed_data.pos..removed_data.pos + removed_data.size).rev() {
+                items.remove(i as usize);
+                dst_changes.push(VecDiff::RemoveAt { index: i as usize });
+            }
+        }
+
+        VecDiff::Replace { index, value } => {
+            // we are replacing a single sub-collection
+
+            handle_source_change(
+                VecDiff::RemoveAt { index },
+                f,
+                items_rc,
+                sub_collection_data_rc,
+                sub_collection_data_indexes_rc,
+                changed_event_rc,
+                dst_changes,
+            );
+            handle_source_change(
+                VecDiff::InsertAt { index, value },
+                f,
+                items_rc,
+                sub_collection_data_rc,
+                sub_collection_data_indexes_rc,
+                changed_event_rc,
+                dst_changes,
+            );
+        }
 
-                    // remove elements
-                    let mut items = items_rc.borrow_mut();
-                    let changed_event = changed_event_rc.borrow();
-                    for i in (removed_data.pos..removed_data.pos + removed_data.size).rev() {
-                        items.remove(i as usize);
-                        changed_event.emit(VecDiff::RemoveAt { index: i as usize });
+        VecDiff::Move {
+            old_index,
+            new_index,
+        } => {
+            // we are moving a single sub-collection
+            // (all its items are moved, nothing is recreated)
+
+            let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+            let old_pos = sub_collection_data[old_index].pos as usize;
+            let size = sub_collection_data[old_index].size as usize;
+
+            let moved_data = sub_collection_data.remove(old_index);
+            sub_collection_data.insert(new_index, moved_data);
+
+            let mut sub_collection_data_indexes = sub_collection_data_indexes_rc.borrow_mut();
+            let moved_index = sub_collection_data_indexes.remove(old_index);
+            sub_collection_data_indexes.insert(new_index, moved_index);
+            for (i, sub_collection_data_index) in sub_collection_data_indexes.iter().enumerate() {
+                sub_collection_data_index.set(i as i32);
+            }
+
+            // recalculate positions
+            let mut pos = 0;
+            for data in sub_collection_data.iter_mut() {
+                data.pos = pos;
+                pos += data.size;
+            }
+            let new_pos = sub_collection_data[new_index].pos as usize;
+
+            // move items one by one
+            let mut items = items_rc.borrow_mut();
+            for i in 0..size {
+                let item_move = if new_pos < old_pos {
+                    VecDiff::Move {
+                        old_index: old_pos + i,
+                        new_index: new_pos + i,
                     }
-                }
+                } else {
+                    VecDiff::Move {
+                        old_index: old_pos,
+                        new_index: new_pos + size - 1,
+                    }
+                };
+                item_move.clone().apply_to(&mut items);
+                dst_changes.push(item_move);
             }
-        });
-        let event_subscription = self.on_changed(handler);
+        }
 
-        ObservableCollectionFlatMap {
-            items: items_rc,
-            _sub_collection_data: sub_collection_data_rc,
-            _sub_collection_data_indexes: sub_collection_data_indexes_rc,
-            changed_event: changed_event_rc,
-            _items_changed_event_subscription: event_subscription,
+        VecDiff::Batch { changes } => {
+            for change in changes {
+                handle_source_change(
+                    change,
+                    f,
+                    items_rc,
+                    sub_collection_data_rc,
+                    sub_collection_data_indexes_rc,
+                    changed_event_rc,
+                    dst_changes,
+                );
+            }
         }
     }
 }
@@ -240,72 +355,144 @@ where
     TDstColl: ObservableCollection<TDst> + IntoIterator<Item = TDst>,
 {
     let handler = Box::new({
-        move |changed_args: VecDiff<TDst>| match changed_args {
-            VecDiff::Clear {} => {
-                // clear all elements from current sub-collection
-                // (but not remove it)
-
-                let collection_index = sub_collection_data_index_rc.get() as usize;
-
-                // update sub_collection_data
-                let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
-                let pos = sub_collection_data[collection_index].pos;
-                let size = sub_collection_data[collection_index].size;
-                sub_collection_data[collection_index].size = 0;
-                for i in collection_index + 1..sub_collection_data.len() {
-                    sub_collection_data[i].pos -= size;
-                }
-
-                // remove items
-                let mut items = items_rc.borrow_mut();
-                let changed_event = changed_event_rc.borrow();
-                for i in (pos..pos + size).rev() {
-                    items.remove(i as usize);
-                    changed_event.emit(VecDiff::RemoveAt { index: i as usize });
-                }
+        move |changed_args: VecDiff<TDst>| {
+            let is_batch = matches!(changed_args, VecDiff::Batch { .. });
+            let mut dst_changes = Vec::new();
+            handle_subcollection_change(
+                changed_args,
+                &items_rc,
+                &sub_collection_data_rc,
+                sub_collection_data_index_rc.get() as usize,
+                &mut dst_changes,
+            );
+            emit_changes(&changed_event_rc.borrow(), dst_changes, is_batch);
+        }
+    });
+    new_items.on_changed(handler)
+}
+
+///
+/// Handles change inside a single sub-collection.
+/// Changes of the output collection are appended to `dst_changes`.
+///
+fn handle_subcollection_change<TDst>(
+    changed_args: VecDiff<TDst>,
+    items_rc: &Rc<RefCell<Vec<TDst>>>,
+    sub_collection_data_rc: &Rc<RefCell<Vec<SubCollectionData>>>,
+    collection_index: usize,
+    dst_changes: &mut Vec<VecDiff<TDst>>,
+) where
+    TDst: Clone + 'static,
+{
+    match changed_args {
+        VecDiff::Clear {} => {
+            // clear all elements from current sub-collection
+            // (but not remove it)
+
+            // update sub_collection_data
+            let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+            let pos = sub_collection_data[collection_index].pos;
+            let size = sub_collection_data[collection_index].size;
+            sub_collection_data[collection_index].size = 0;
+            for i in collection_index + 1..sub_collection_data.len() {
+                sub_collection_data[i].pos -= size;
             }
 
-            VecDiff::InsertAt { index, value } => {
-                let collection_index = sub_collection_data_index_rc.get() as usize;
-
-                // update sub_collection_data
-                let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
-                let pos = sub_collection_data[collection_index].pos as usize;
-                sub_collection_data[collection_index].size += 1;
-                for i in collection_index + 1..sub_collection_data.len() {
-                    sub_collection_data[i].pos += 1;
-                }
-
-                // insert item
-                let mut items = items_rc.borrow_mut();
-                items.insert(pos + index, value.clone());
-                changed_event_rc.borrow().emit(VecDiff::InsertAt {
-                    index: pos + index,
-                    value,
-                });
+            // remove items
+            let mut items = items_rc.borrow_mut();
+            for i in (pos..pos + size).rev() {
+                items.remove(i as usize);
+                dst_changes.push(VecDiff::RemoveAt { index: i as usize });
             }
+        }
 
-            VecDiff::RemoveAt { index } => {
-                let collection_index = sub_collection_data_index_rc.get() as usize;
-
-                // update sub_collection_data
-                let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
-                let pos = sub_collection_data[collection_index].pos as usize;
-                sub_collection_data[collection_index].size -= 1;
-                for i in collection_index + 1..sub_collection_data.len() {
-                    sub_collection_data[i].pos -= 1;
-                }
-
-                // remove item
-                let mut items = items_rc.borrow_mut();
-                items.remove(pos + index);
-                changed_event_rc
-                    .borrow()
-                    .emit(VecDiff::RemoveAt { index: pos + index });
+        VecDiff::InsertAt { index, value } => {
+            // update sub_collection_data
+            let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+            let pos = sub_collection_data[collection_index].pos as usize;
+            sub_collection_data[collection_index].size += 1;
+            for i in collection_index + 1..sub_collection_data.len() {
+                sub_collection_data[i].pos += 1;
             }
+
+            // insert item
+            let mut items = items_rc.borrow_mut();
+            items.insert(pos + index, value.clone());
+            dst_changes.push(VecDiff::InsertAt {
+                index: pos + index,
+                value,
+            });
         }
-    });
-    new_items.on_changed(handler)
+
+        VecDiff::RemoveAt { index } => {
+            // update sub_collection_data
+            let mut sub_collection_data = sub_collection_data_rc.borrow_mut();
+            let pos = sub_collection_data[collection_index].pos as usize;
+            sub_collection_data[collection_index].size -= 1;
+            for i in collection_index + 1..sub_collection_data.len() {
+                sub_collection_data[i].pos -= 1;
+            }
+
+            // remove item
+            let mut items = items_rc.borrow_mut();
+            items.remove(pos + index);
+            dst_changes.push(VecDiff::RemoveAt { index: pos + index });
+        }
+
+        VecDiff::Replace { index, value } => {
+            let pos = sub_collection_data_rc.borrow()[collection_index].pos as usize;
+
+            // replace item
+            items_rc.borrow_mut()[pos + index] = value.clone();
+            dst_changes.push(VecDiff::Replace {
+                index: pos + index,
+                value,
+            });
+        }
+
+        VecDiff::Move {
+            old_index,
+            new_index,
+        } => {
+            let pos = sub_collection_data_rc.borrow()[collection_index].pos as usize;
+
+            // move item
+            let item_move = VecDiff::Move {
+                old_index: pos + old_index,
+                new_index: pos + new_index,
+            };
+            item_move.clone().apply_to(&mut items_rc.borrow_mut());
+            dst_changes.push(item_move);
+        }
+
+        VecDiff::Batch { changes } => {
+            for change in changes {
+                handle_subcollection_change(
+                    change,
+                    items_rc,
+                    sub_collection_data_rc,
+                    collection_index,
+                    dst_changes,
+                );
+            }
+        }
+    }
+}
+
+///
+/// Emits output changes, as a single batch event if the source change was a batch.
+///
+fn emit_changes<T>(changed_event: &Event<VecDiff<T>>, changes: Vec<VecDiff<T>>, is_batch: bool)
+where
+    T: Clone + 'static,
+{
+    if is_batch {
+        changed_event.emit(VecDiff::Batch { changes });
+    } else {
+        for change in changes {
+            changed_event.emit(change);
+        }
+    }
 }
 
 impl<TSrc, TSrcColl> ObservableCollectionFlatMapExt<TSrc> for TSrcColl
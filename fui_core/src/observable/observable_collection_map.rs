@@ -1,5 +1,5 @@
 use crate::{Event, ObservableCollection, Subscription, VecDiff};
-use std::cell::{RefCell, RefMut};
+use std::cell::RefCell;
 use std::rc::Rc;
 
 ///
@@ -55,28 +55,10 @@ where
         let handler = Box::new({
             let items_rc = items_rc.clone();
             let changed_event_rc = changed_event_rc.clone();
-            move |changed_args| match changed_args {
-                VecDiff::Clear {} => {
-                    (items_rc.borrow_mut() as RefMut<'_, Vec<TDst>>).clear();
-                    changed_event_rc.borrow().emit(VecDiff::Clear {});
-                }
-
-                VecDiff::InsertAt { index, value } => {
-                    let mut vec: RefMut<'_, Vec<TDst>> = items_rc.borrow_mut();
-                    let new_item = f(&value);
-                    let new_item_clone = new_item.clone();
-                    vec.insert(index, new_item);
-
-                    changed_event_rc.borrow().emit(VecDiff::InsertAt {
-                        index,
-                        value: new_item_clone,
-                    });
-                }
-
-                VecDiff::RemoveAt { index } => {
-                    items_rc.borrow_mut().remove(index);
-                    changed_event_rc.borrow().emit(VecDiff::RemoveAt { index });
-                }
+            move |changed_args: VecDiff<T>| {
+                let mapped_args = changed_args.map(|value| f(&value));
+                mapped_args.clone().apply_to(&mut items_rc.borrow_mut());
+                changed_event_rc.borrow().emit(mapped_args);
             }
         });
         let event_subscription = self.on_changed(handler);
@@ -33,50 +33,27 @@ impl<T: 'static + Clone> ObservableComposite<T> {
 
             let lengths_clone = lengths_rc.clone();
             let changed_event_clone = changed_event.clone();
-            let handler = Box::new(move |changed_args| {
-                // calculate offset, which is sum of length of all previous sources
-                let offset: usize = lengths_clone.borrow().iter().take(source_index).sum();
-
-                // apply offset to event args and update lengths collection
-                match changed_args {
-                    VecDiff::Clear {} => {
-                        let number_of_sources = lengths_clone.borrow().len();
-                        let mut lengths = lengths_clone.borrow_mut();
-                        let other_collections_size: usize = (0..number_of_sources)
-                            .filter(|i| *i != source_index)
-                            .map(|i| lengths[i])
-                            .sum();
-                        if other_collections_size == 0 {
-                            changed_event_clone.borrow().emit(VecDiff::Clear {});
-                        } else {
-                            for i in (0..lengths[source_index]).rev() {
-                                changed_event_clone
-                                    .borrow()
-                                    .emit(VecDiff::RemoveAt { index: offset + i });
-                            }
-                            lengths[source_index] = 0;
-                        }
-                    }
-
-                    VecDiff::InsertAt { index, value } => {
-                        lengths_clone.borrow_mut()[source_index] += 1;
-                        changed_event_clone.borrow().emit(VecDiff::InsertAt {
-                            index: offset + index,
-                            value,
-                        });
-                    }
+            let handler = Box::new(move |changed_args: VecDiff<T>| {
+                let is_batch = matches!(changed_args, VecDiff::Batch { .. });
 
-                    VecDiff::RemoveAt { index } => {
-                        let mut lengths = lengths_clone.borrow_mut();
-                        if lengths[source_index] > 0 {
-                            lengths[source_index] -= 1;
-                        }
+                let mut translated_args = Vec::new();
+                translate_source_change(
+                    changed_args,
+                    source_index,
+                    &mut lengths_clone.borrow_mut(),
+                    &mut translated_args,
+                );
 
-                        changed_event_clone.borrow().emit(VecDiff::RemoveAt {
-                            index: offset + index,
-                        });
+                let changed_event = changed_event_clone.borrow();
+                if is_batch {
+                    changed_event.emit(VecDiff::Batch {
+                        changes: translated_args,
+                    });
+                } else {
+                    for args in translated_args {
+                        changed_event.emit(args);
                     }
-                };
+                }
             });
 
             if let Some(subscription) = source.on_changed(handler) {
@@ -91,6 +68,77 @@ impl<T: 'static + Clone> ObservableComposite<T> {
     }
 }
 
+///
+/// Applies offset of the source collection to event args
+/// and updates lengths collection.
+///
+fn translate_source_change<T>(
+    changed_args: VecDiff<T>,
+    source_index: usize,
+    lengths: &mut [usize],
+    result: &mut Vec<VecDiff<T>>,
+) {
+    // calculate offset, which is sum of length of all previous sources
+    let offset: usize = lengths.iter().take(source_index).sum();
+
+    match changed_args {
+        VecDiff::Clear {} => {
+            let other_collections_size: usize = (0..lengths.len())
+                .filter(|i| *i != source_index)
+                .map(|i| lengths[i])
+                .sum();
+            if other_collections_size == 0 {
+                result.push(VecDiff::Clear {});
+            } else {
+                for i in (0..lengths[source_index]).rev() {
+                    result.push(VecDiff::RemoveAt { index: offset + i });
+                }
+            }
+            lengths[source_index] = 0;
+        }
+
+        VecDiff::InsertAt { index, value } => {
+            lengths[source_index] += 1;
+            result.push(VecDiff::InsertAt {
+                index: offset + index,
+                value,
+            });
+        }
+
+        VecDiff::RemoveAt { index } => {
+            if lengths[source_index] > 0 {
+                lengths[source_index] -= 1;
+            }
+            result.push(VecDiff::RemoveAt {
+                index: offset + index,
+            });
+        }
+
+        VecDiff::Replace { index, value } => {
+            result.push(VecDiff::Replace {
+                index: offset + index,
+                value,
+            });
+        }
+
+        VecDiff::Move {
+            old_index,
+            new_index,
+        } => {
+            result.push(VecDiff::Move {
+                old_index: offset + old_index,
+                new_index: offset + new_index,
+            });
+        }
+
+        VecDiff::Batch { changes } => {
+            for change in changes {
+                translate_source_change(change, source_index, lengths, result);
+            }
+        }
+    };
+}
+
 impl<T: 'static + Clone> ObservableCollection<T> for ObservableComposite<T> {
     fn len(&self) -> usize {
         self.sources.iter().map(|s| s.len()).sum()
@@ -0,0 +1,233 @@
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{observable::event::Event, EventSubscription};
+
+///
+/// Describes change of the observable map.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapDiff<K, V> {
+    Clear {},
+
+    Insert { key: K, value: V },
+
+    Remove { key: K },
+
+    Update { key: K, value: V },
+}
+
+///
+/// Observable collection of values accessed by key.
+///
+/// Keys are kept in insertion order. Use `values()` or `values_sorted_by()`
+/// to show the values as an ordered `ObservableCollection`.
+///
+/// Example:
+///
+/// let mut devices = ObservableMap::new();
+/// devices.insert(device_id, Rc::new(RefCell::new(DeviceViewModel::new(device))));
+///
+/// ui! { Vertical { &devices.values() } }
+///
+pub struct ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    items: HashMap<K, MapEntry<V>>,
+
+    // keys in insertion order (None for removed keys until the next compaction)
+    keys: Vec<Option<K>>,
+    removed_count: usize,
+
+    changed_event: RefCell<Event<MapDiff<K, V>>>,
+}
+
+struct MapEntry<V> {
+    // position of the key in the keys vector
+    position: usize,
+    value: V,
+}
+
+impl<K, V> ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    pub fn new() -> Self {
+        ObservableMap {
+            items: HashMap::new(),
+            keys: Vec::new(),
+            removed_count: 0,
+            changed_event: RefCell::new(Event::new()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.items.get(key).map(|entry| entry.value.clone())
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.items.contains_key(key)
+    }
+
+    ///
+    /// Keys in insertion order.
+    ///
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.keys.iter().flatten()
+    }
+
+    ///
+    /// Key-value pairs in insertion order.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.keys().map(move |key| (key, &self.items[key].value))
+    }
+
+    #[track_caller]
+    pub fn on_changed<F>(&self, f: F) -> EventSubscription
+    where
+        F: 'static + FnMut(MapDiff<K, V>),
+    {
+        self.changed_event.borrow_mut().subscribe(f)
+    }
+
+    ///
+    /// Inserts or updates the value.
+    ///
+    /// Returns the old value if the key was present.
+    /// Updated value keeps its position in the insertion order.
+    ///
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (old_value, event_args) = if let Some(entry) = self.items.get_mut(&key) {
+            let old_value = std::mem::replace(&mut entry.value, value.clone());
+            (Some(old_value), MapDiff::Update { key, value })
+        } else {
+            self.push_new(key.clone(), value.clone());
+            (None, MapDiff::Insert { key, value })
+        };
+        self.changed_event.borrow().emit(event_args);
+        old_value
+    }
+
+    ///
+    /// Changes the value in place.
+    ///
+    /// Returns false if the key is not present.
+    ///
+    pub fn update<Q, F>(&mut self, key: &Q, f: F) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        F: FnOnce(&mut V),
+    {
+        if let Some(entry) = self.items.get_mut(key) {
+            f(&mut entry.value);
+            let key = self.keys[entry.position].clone().unwrap();
+            let value = entry.value.clone();
+            self.changed_event
+                .borrow()
+                .emit(MapDiff::Update { key, value });
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (key, entry) = self.items.remove_entry(key)?;
+        self.keys[entry.position] = None;
+        self.removed_count += 1;
+        if self.removed_count > self.keys.len() / 2 {
+            self.compact_keys();
+        }
+        self.changed_event.borrow().emit(MapDiff::Remove { key });
+        Some(entry.value)
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.keys.clear();
+        self.removed_count = 0;
+        self.changed_event.borrow().emit(MapDiff::Clear {});
+    }
+
+    fn push_new(&mut self, key: K, value: V) {
+        self.items.insert(
+            key.clone(),
+            MapEntry {
+                position: self.keys.len(),
+                value,
+            },
+        );
+        self.keys.push(Some(key));
+    }
+
+    ///
+    /// Removes the slots of the removed keys.
+    ///
+    /// Called when more than half of the slots are empty,
+    /// so the removal has amortized constant cost.
+    ///
+    fn compact_keys(&mut self) {
+        self.keys.retain(|key| key.is_some());
+        for (position, key) in self.keys.iter().flatten().enumerate() {
+            if let Some(entry) = self.items.get_mut(key) {
+                entry.position = position;
+            }
+        }
+        self.removed_count = 0;
+    }
+}
+
+impl<K, V> Default for ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    fn default() -> Self {
+        ObservableMap::new()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = ObservableMap::new();
+        for (key, value) in iter {
+            if let Some(entry) = map.items.get_mut(&key) {
+                entry.value = value;
+            } else {
+                map.push_new(key, value);
+            }
+        }
+        map
+    }
+}
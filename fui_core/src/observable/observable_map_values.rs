@@ -0,0 +1,180 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::{Event, MapDiff, ObservableCollection, ObservableMap, Subscription, VecDiff};
+
+type Compare<K, V> = Box<dyn Fn(&(K, V), &(K, V)) -> Ordering>;
+
+///
+/// Values of the ObservableMap exposed as an ordered observable collection.
+///
+/// Values are ordered by insertion order or by the sort key.
+///
+pub struct ObservableMapValues<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    entries: Rc<RefCell<Vec<(K, V)>>>,
+    changed_event: Rc<RefCell<Event<VecDiff<V>>>>,
+    _map_changed_event_subscription: Subscription,
+}
+
+impl<K, V> ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    ///
+    /// Values in insertion order.
+    ///
+    /// Example:
+    ///
+    /// ui! { DropDown { items: Box::new(vm.devices.values()) } }
+    ///
+    pub fn values(&self) -> ObservableMapValues<K, V> {
+        ObservableMapValues::new(self, None)
+    }
+
+    ///
+    /// Values ordered by the sort key.
+    ///
+    /// Values with equal sort keys are kept in insertion order.
+    ///
+    pub fn values_sorted_by<S, F>(&self, sort_key: F) -> ObservableMapValues<K, V>
+    where
+        S: Ord,
+        F: 'static + Fn(&K, &V) -> S,
+    {
+        ObservableMapValues::new(
+            self,
+            Some(Box::new(move |(k1, v1), (k2, v2)| {
+                sort_key(k1, v1).cmp(&sort_key(k2, v2))
+            })),
+        )
+    }
+}
+
+impl<K, V> ObservableMapValues<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    fn new(map: &ObservableMap<K, V>, compare: Option<Compare<K, V>>) -> Self {
+        let mut entries: Vec<(K, V)> = map
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        if let Some(compare) = &compare {
+            entries.sort_by(compare);
+        }
+        let entries = Rc::new(RefCell::new(entries));
+
+        let changed_event = Rc::new(RefCell::new(Event::new()));
+
+        let handler = {
+            let entries = entries.clone();
+            let changed_event = changed_event.clone();
+            move |changed_args: MapDiff<K, V>| {
+                let mut entries = entries.borrow_mut();
+
+                // position of the new entry (after equal entries)
+                let insert_position = |entries: &Vec<(K, V)>, entry: &(K, V)| match &compare {
+                    Some(compare) => {
+                        entries.partition_point(|e| compare(e, entry) != Ordering::Greater)
+                    }
+                    None => entries.len(),
+                };
+
+                let event_args = match changed_args {
+                    MapDiff::Clear {} => {
+                        entries.clear();
+                        Some(VecDiff::Clear {})
+                    }
+
+                    MapDiff::Insert { key, value } => {
+                        let entry = (key, value.clone());
+                        let index = insert_position(&entries, &entry);
+                        entries.insert(index, entry);
+                        Some(VecDiff::InsertAt { index, value })
+                    }
+
+                    MapDiff::Remove { key } => {
+                        entries.iter().position(|(k, _)| *k == key).map(|index| {
+                            entries.remove(index);
+                            VecDiff::RemoveAt { index }
+                        })
+                    }
+
+                    MapDiff::Update { key, value } => {
+                        entries.iter().position(|(k, _)| *k == key).map(|index| {
+                            entries[index].1 = value.clone();
+                            let replace = VecDiff::Replace { index, value };
+                            if compare.is_none() {
+                                return replace;
+                            }
+
+                            // the sort key may have changed
+                            let entry = entries.remove(index);
+                            let new_index = insert_position(&entries, &entry);
+                            entries.insert(new_index, entry);
+                            if new_index == index {
+                                replace
+                            } else {
+                                VecDiff::Batch {
+                                    changes: vec![
+                                        replace,
+                                        VecDiff::Move {
+                                            old_index: index,
+                                            new_index,
+                                        },
+                                    ],
+                                }
+                            }
+                        })
+                    }
+                };
+
+                // emitted when the entries are not borrowed
+                drop(entries);
+                if let Some(event_args) = event_args {
+                    changed_event.borrow().emit(event_args);
+                }
+            }
+        };
+        let map_changed_event_subscription = map.on_changed(handler);
+
+        ObservableMapValues {
+            entries,
+            changed_event,
+            _map_changed_event_subscription: Subscription::EventSubscription(
+                map_changed_event_subscription,
+            ),
+        }
+    }
+}
+
+impl<K, V> ObservableCollection<V> for ObservableMapValues<K, V>
+where
+    K: 'static + Clone + Eq + Hash,
+    V: 'static + Clone,
+{
+    fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    fn get(&self, index: usize) -> Option<V> {
+        self.entries
+            .borrow()
+            .get(index)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn on_changed(&self, f: Box<dyn FnMut(VecDiff<V>)>) -> Option<Subscription> {
+        Some(Subscription::EventSubscription(
+            self.changed_event.borrow_mut().subscribe(f),
+        ))
+    }
+}
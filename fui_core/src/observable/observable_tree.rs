@@ -0,0 +1,290 @@
+use std::cell::RefCell;
+
+use crate::{Event, EventSubscription};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TreeNodeId(usize);
+
+impl TreeNodeId {
+    fn unassigned() -> Self {
+        TreeNodeId(0)
+    }
+}
+
+///
+/// Node of the ObservableTree.
+///
+/// The node has a value, children and the expanded state.
+/// Id is assigned when the node is inserted to the tree.
+///
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeNode<T> {
+    #[cfg_attr(feature = "serde", serde(skip, default = "TreeNodeId::unassigned"))]
+    id: TreeNodeId,
+    pub value: T,
+    pub is_expanded: bool,
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    pub fn new(value: T) -> Self {
+        TreeNode {
+            id: TreeNodeId::unassigned(),
+            value,
+            is_expanded: false,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<TreeNode<T>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    pub fn id(&self) -> TreeNodeId {
+        self.id
+    }
+}
+
+///
+/// Describes change of the ObservableTree.
+///
+/// Nodes are addressed by paths (indexes of the nodes on every level, starting from the roots).
+/// The path of the inserted node points to the position where it is inserted.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum TreeDiff<T> {
+    Clear {},
+
+    Insert { path: Vec<usize>, node: TreeNode<T> },
+
+    Remove { path: Vec<usize> },
+
+    Replace { path: Vec<usize>, value: T },
+
+    SetExpanded { path: Vec<usize>, is_expanded: bool },
+}
+
+impl<T> TreeDiff<T> {
+    ///
+    /// Applies the change to the list of root nodes.
+    ///
+    pub fn apply_to(self, roots: &mut Vec<TreeNode<T>>) {
+        match self {
+            TreeDiff::Clear {} => roots.clear(),
+
+            TreeDiff::Insert { path, node } => {
+                let (index, parent_path) = path.split_last().unwrap();
+                children_mut(roots, parent_path).insert(*index, node);
+            }
+
+            TreeDiff::Remove { path } => {
+                let (index, parent_path) = path.split_last().unwrap();
+                children_mut(roots, parent_path).remove(*index);
+            }
+
+            TreeDiff::Replace { path, value } => node_mut(roots, &path).value = value,
+
+            TreeDiff::SetExpanded { path, is_expanded } => {
+                node_mut(roots, &path).is_expanded = is_expanded
+            }
+        }
+    }
+}
+
+fn node<'a, T>(roots: &'a [TreeNode<T>], path: &[usize]) -> Option<&'a TreeNode<T>> {
+    let (index, parent_path) = path.split_last()?;
+    let siblings = match parent_path.is_empty() {
+        true => roots,
+        false => &node(roots, parent_path)?.children,
+    };
+    siblings.get(*index)
+}
+
+fn node_mut<'a, T>(roots: &'a mut [TreeNode<T>], path: &[usize]) -> &'a mut TreeNode<T> {
+    let mut node = &mut roots[path[0]];
+    for index in &path[1..] {
+        node = &mut node.children[*index];
+    }
+    node
+}
+
+fn children_mut<'a, T>(
+    roots: &'a mut Vec<TreeNode<T>>,
+    path: &[usize],
+) -> &'a mut Vec<TreeNode<T>> {
+    if path.is_empty() {
+        roots
+    } else {
+        &mut node_mut(roots, path).children
+    }
+}
+
+///
+/// Observable hierarchical collection.
+///
+/// Every node has children and the expanded state. Changes are notified
+/// with path-addressed `TreeDiff`s. Methods panic when the path is invalid.
+///
+/// Visible nodes (nodes with all the ancestors expanded) can be shown
+/// with the `visible_rows()` adapter.
+///
+/// Example:
+///
+/// let mut tree = ObservableTree::new();
+/// tree.push(&[], TreeNode::new("src".to_string()).with_children(vec![
+///     TreeNode::new("main.rs".to_string()),
+/// ]));
+/// tree.set_expanded(&[0], true);
+///
+pub struct ObservableTree<T: 'static + Clone> {
+    roots: Vec<TreeNode<T>>,
+    next_id: usize,
+    changed_event: RefCell<Event<TreeDiff<T>>>,
+}
+
+impl<T: 'static + Clone> ObservableTree<T> {
+    pub fn new() -> Self {
+        ObservableTree {
+            roots: Vec::new(),
+
+            // id 0 is used by nodes not inserted to the tree
+            next_id: 1,
+            changed_event: RefCell::new(Event::new()),
+        }
+    }
+
+    pub fn roots(&self) -> &[TreeNode<T>] {
+        &self.roots
+    }
+
+    pub fn get(&self, path: &[usize]) -> Option<&TreeNode<T>> {
+        node(&self.roots, path)
+    }
+
+    ///
+    /// Returns current path of the node.
+    ///
+    pub fn path_of(&self, id: TreeNodeId) -> Option<Vec<usize>> {
+        fn find<T>(nodes: &[TreeNode<T>], id: TreeNodeId, path: &mut Vec<usize>) -> bool {
+            for (index, node) in nodes.iter().enumerate() {
+                path.push(index);
+                if node.id == id || find(&node.children, id, path) {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        }
+
+        let mut path = Vec::new();
+        if find(&self.roots, id, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    #[track_caller]
+    pub fn on_changed<F>(&self, f: F) -> EventSubscription
+    where
+        F: 'static + FnMut(TreeDiff<T>),
+    {
+        self.changed_event.borrow_mut().subscribe(f)
+    }
+
+    ///
+    /// Inserts the node (with its children) at the path.
+    ///
+    pub fn insert(&mut self, path: &[usize], mut node: TreeNode<T>) {
+        self.assign_ids(&mut node);
+        self.apply(TreeDiff::Insert {
+            path: path.to_vec(),
+            node,
+        });
+    }
+
+    ///
+    /// Adds the node as the last child of the parent (or as the last root
+    /// when the parent path is empty) and returns its path.
+    ///
+    pub fn push(&mut self, parent_path: &[usize], node: TreeNode<T>) -> Vec<usize> {
+        let index = if parent_path.is_empty() {
+            self.roots.len()
+        } else {
+            node_mut(&mut self.roots, parent_path).children.len()
+        };
+
+        let mut path = parent_path.to_vec();
+        path.push(index);
+        self.insert(&path, node);
+        path
+    }
+
+    pub fn remove(&mut self, path: &[usize]) -> TreeNode<T> {
+        let node = node_mut(&mut self.roots, path).clone();
+        self.apply(TreeDiff::Remove {
+            path: path.to_vec(),
+        });
+        node
+    }
+
+    ///
+    /// Replaces the value of the node and returns the old one.
+    ///
+    pub fn set(&mut self, path: &[usize], value: T) -> T {
+        let old_value = node_mut(&mut self.roots, path).value.clone();
+        self.apply(TreeDiff::Replace {
+            path: path.to_vec(),
+            value,
+        });
+        old_value
+    }
+
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        self.get(path).is_some_and(|node| node.is_expanded)
+    }
+
+    pub fn set_expanded(&mut self, path: &[usize], is_expanded: bool) {
+        if node_mut(&mut self.roots, path).is_expanded != is_expanded {
+            self.apply(TreeDiff::SetExpanded {
+                path: path.to_vec(),
+                is_expanded,
+            });
+        }
+    }
+
+    pub fn toggle_expanded(&mut self, path: &[usize]) {
+        let is_expanded = self.is_expanded(path);
+        self.set_expanded(path, !is_expanded);
+    }
+
+    pub fn clear(&mut self) {
+        self.apply(TreeDiff::Clear {});
+    }
+
+    fn apply(&mut self, change: TreeDiff<T>) {
+        change.clone().apply_to(&mut self.roots);
+        self.changed_event.borrow().emit(change);
+    }
+
+    fn assign_ids(&mut self, node: &mut TreeNode<T>) {
+        node.id = TreeNodeId(self.next_id);
+        self.next_id += 1;
+        for child in &mut node.children {
+            self.assign_ids(child);
+        }
+    }
+}
+
+impl<T: 'static + Clone> Default for ObservableTree<T> {
+    fn default() -> Self {
+        ObservableTree::new()
+    }
+}
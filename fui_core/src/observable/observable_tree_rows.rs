@@ -0,0 +1,271 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{
+    Event, EventSubscription, ObservableCollection, ObservableTree, Subscription, TreeDiff,
+    TreeNode, TreeNodeId, VecDiff,
+};
+
+///
+/// Visible node of the ObservableTree.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow<T> {
+    pub id: TreeNodeId,
+    pub value: T,
+    pub depth: usize,
+    pub has_children: bool,
+    pub is_expanded: bool,
+}
+
+///
+/// Flattened view of the ObservableTree.
+///
+/// Contains rows of all the nodes with expanded ancestors (in depth-first order).
+/// It is updated when nodes are inserted, removed, replaced, expanded or collapsed.
+/// Only rows of the changed node and its subtree are created or removed
+/// (finding their position is linear in the number of preceding visible rows).
+///
+/// Example:
+///
+/// let rows = vm.tree.visible_rows();
+/// ui! {
+///     Vertical {
+///         &rows.map(|row: &TreeRow<String>| ui! {
+///             Text { Margin: Thickness::left(row.depth as f32 * 16.0f32), text: row.value.clone() }
+///         }),
+///     }
+/// }
+///
+pub struct ObservableTreeRows<T: 'static + Clone> {
+    inner: Rc<RefCell<ObservableTreeRowsInner<T>>>,
+    changed_event: Rc<RefCell<Event<VecDiff<TreeRow<T>>>>>,
+    _tree_changed_subscription: EventSubscription,
+}
+
+struct ObservableTreeRowsInner<T> {
+    // copy of the tree nodes
+    roots: Vec<TreeNode<T>>,
+
+    // current content of the view
+    items: Vec<TreeRow<T>>,
+}
+
+impl<T: 'static + Clone> ObservableTree<T> {
+    ///
+    /// Returns flattened view of the visible nodes.
+    ///
+    pub fn visible_rows(&self) -> ObservableTreeRows<T> {
+        let mut items = Vec::new();
+        ObservableTreeRowsInner::calc_rows(self.roots(), 0, &mut items);
+        let inner = ObservableTreeRowsInner {
+            roots: self.roots().to_vec(),
+            items,
+        };
+
+        let inner = Rc::new(RefCell::new(inner));
+        let changed_event = Rc::new(RefCell::new(Event::new()));
+
+        let tree_changed_subscription = self.on_changed({
+            let inner = inner.clone();
+            let changed_event = changed_event.clone();
+            move |changed_args| {
+                let mut changes = inner.borrow_mut().apply_tree_change(changed_args);
+                match changes.len() {
+                    0 => (),
+                    1 => changed_event.borrow().emit(changes.remove(0)),
+                    _ => changed_event.borrow().emit(VecDiff::Batch { changes }),
+                }
+            }
+        });
+
+        ObservableTreeRows {
+            inner,
+            changed_event,
+            _tree_changed_subscription: tree_changed_subscription,
+        }
+    }
+}
+
+impl<T: 'static + Clone> ObservableTreeRows<T> {
+    pub fn len(&self) -> usize {
+        self.inner.borrow().items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<TreeRow<T>> {
+        self.inner.borrow().items.get(index)
+    }
+}
+
+impl<T: 'static + Clone> ObservableTreeRowsInner<T> {
+    ///
+    /// Applies the tree change and returns the changes of the rows.
+    ///
+    fn apply_tree_change(&mut self, changed_args: TreeDiff<T>) -> Vec<VecDiff<TreeRow<T>>> {
+        let mut changes = Vec::new();
+
+        match &changed_args {
+            TreeDiff::Clear {} => {
+                if !self.items.is_empty() {
+                    changes.push(VecDiff::Clear {});
+                }
+            }
+
+            TreeDiff::Insert { path, node } => {
+                if let Some(index) = row_index(&self.roots, path) {
+                    let mut rows = Vec::new();
+                    Self::calc_rows(std::slice::from_ref(node), path.len() - 1, &mut rows);
+                    for (offset, row) in rows.into_iter().enumerate() {
+                        changes.push(VecDiff::InsertAt {
+                            index: index + offset,
+                            value: row,
+                        });
+                    }
+                }
+            }
+
+            TreeDiff::Remove { path } => {
+                if let Some(index) = row_index(&self.roots, path) {
+                    let count = visible_count(get_node(&self.roots, path));
+                    changes.extend((0..count).map(|_| VecDiff::RemoveAt { index }));
+                }
+            }
+
+            TreeDiff::Replace { .. } => (),
+
+            TreeDiff::SetExpanded { path, is_expanded } => {
+                if let Some(index) = row_index(&self.roots, path) {
+                    let node = get_node(&self.roots, path);
+                    if *is_expanded {
+                        let mut rows = Vec::new();
+                        Self::calc_rows(&node.children, path.len(), &mut rows);
+                        for (offset, row) in rows.into_iter().enumerate() {
+                            changes.push(VecDiff::InsertAt {
+                                index: index + 1 + offset,
+                                value: row,
+                            });
+                        }
+                    } else {
+                        let count = visible_count(node) - 1;
+                        changes.extend((0..count).map(|_| VecDiff::RemoveAt { index: index + 1 }));
+                    }
+                }
+            }
+        }
+
+        // the row of the changed node (or of the parent, which can get or lose children)
+        let updated_row = match &changed_args {
+            TreeDiff::Replace { path, .. } | TreeDiff::SetExpanded { path, .. } => {
+                Some((path.clone(), true))
+            }
+            TreeDiff::Insert { path, .. } | TreeDiff::Remove { path } if path.len() > 1 => {
+                Some((path[..path.len() - 1].to_vec(), false))
+            }
+            _ => None,
+        };
+
+        changed_args.apply_to(&mut self.roots);
+        for change in &changes {
+            change.clone().apply_to(&mut self.items);
+        }
+
+        if let Some((path, is_changed)) = updated_row {
+            if let Some(index) = row_index(&self.roots, &path) {
+                let new_row = Self::row(get_node(&self.roots, &path), path.len() - 1);
+                if is_changed || self.items[index].has_children != new_row.has_children {
+                    self.items[index] = new_row.clone();
+                    changes.push(VecDiff::Replace {
+                        index,
+                        value: new_row,
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+
+    fn row(node: &TreeNode<T>, depth: usize) -> TreeRow<T> {
+        TreeRow {
+            id: node.id(),
+            value: node.value.clone(),
+            depth,
+            has_children: !node.children.is_empty(),
+            is_expanded: node.is_expanded,
+        }
+    }
+
+    fn calc_rows(nodes: &[TreeNode<T>], depth: usize, rows: &mut Vec<TreeRow<T>>) {
+        for node in nodes {
+            rows.push(Self::row(node, depth));
+            if node.is_expanded {
+                Self::calc_rows(&node.children, depth + 1, rows);
+            }
+        }
+    }
+}
+
+///
+/// Returns index of the row of the node at the path
+/// (or of the row inserted at the path) if it is visible.
+///
+fn row_index<T>(roots: &[TreeNode<T>], path: &[usize]) -> Option<usize> {
+    let mut index = 0;
+    let mut nodes = roots;
+    for (level, node_index) in path.iter().enumerate() {
+        index += nodes[..*node_index]
+            .iter()
+            .map(visible_count)
+            .sum::<usize>();
+        if level == path.len() - 1 {
+            break;
+        }
+
+        let node = &nodes[*node_index];
+        if !node.is_expanded {
+            return None;
+        }
+        index += 1;
+        nodes = &node.children;
+    }
+    Some(index)
+}
+
+///
+/// Number of rows shown for the node (the node and its visible descendants).
+///
+fn visible_count<T>(node: &TreeNode<T>) -> usize {
+    1 + if node.is_expanded {
+        node.children.iter().map(visible_count).sum()
+    } else {
+        0
+    }
+}
+
+fn get_node<'a, T>(roots: &'a [TreeNode<T>], path: &[usize]) -> &'a TreeNode<T> {
+    let mut node = &roots[path[0]];
+    for index in &path[1..] {
+        node = &node.children[*index];
+    }
+    node
+}
+
+impl<T: 'static + Clone> ObservableCollection<TreeRow<T>> for ObservableTreeRows<T> {
+    fn len(&self) -> usize {
+        ObservableTreeRows::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<TreeRow<T>> {
+        ObservableTreeRows::get(self, index)
+    }
+
+    fn on_changed(&self, f: Box<dyn FnMut(VecDiff<TreeRow<T>>)>) -> Option<Subscription> {
+        Some(Subscription::EventSubscription(
+            self.changed_event.borrow_mut().subscribe(f),
+        ))
+    }
+}
@@ -1,6 +1,8 @@
 use std::cell::RefCell;
+use std::hash::Hash;
 use std::iter::FromIterator;
 
+use crate::observable::keyed_diff::diff_keyed;
 use crate::{
     observable::event::Event, EventSubscription, ObservableCollection, Subscription, VecDiff,
 };
@@ -26,6 +28,7 @@ impl<T: 'static + Clone> ObservableVec<T> {
         self.items.get(index).map(|el| el.clone())
     }
 
+    #[track_caller]
     pub fn on_changed<F>(&self, f: F) -> EventSubscription
     where
         F: 'static + FnMut(VecDiff<T>),
@@ -42,6 +45,76 @@ impl<T: 'static + Clone> ObservableVec<T> {
         self.changed_event.borrow().emit(event_args);
     }
 
+    pub fn insert(&mut self, index: usize, value: T) {
+        let event_args = VecDiff::InsertAt {
+            index,
+            value: value.clone(),
+        };
+        self.items.insert(index, value);
+        self.changed_event.borrow().emit(event_args);
+    }
+
+    pub fn remove_at(&mut self, index: usize) -> T {
+        let value = self.items.remove(index);
+        self.changed_event
+            .borrow()
+            .emit(VecDiff::RemoveAt { index });
+        value
+    }
+
+    ///
+    /// Replaces the item at `index` and returns the old one.
+    ///
+    pub fn set(&mut self, index: usize, value: T) -> T {
+        let event_args = VecDiff::Replace {
+            index,
+            value: value.clone(),
+        };
+        let old_value = std::mem::replace(&mut self.items[index], value);
+        self.changed_event.borrow().emit(event_args);
+        old_value
+    }
+
+    ///
+    /// Moves the item from `old_index` so it ends up at `new_index`.
+    ///
+    pub fn move_item(&mut self, old_index: usize, new_index: usize) {
+        if old_index == new_index {
+            return;
+        }
+        let value = self.items.remove(old_index);
+        self.items.insert(new_index, value);
+        self.changed_event.borrow().emit(VecDiff::Move {
+            old_index,
+            new_index,
+        });
+    }
+
+    ///
+    /// Swaps two items.
+    ///
+    /// It is notified as moves, so views of the swapped items are not recreated.
+    ///
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        self.items.swap(a, b);
+
+        let mut changes = vec![VecDiff::Move {
+            old_index: a,
+            new_index: b,
+        }];
+        if b - a > 1 {
+            changes.push(VecDiff::Move {
+                old_index: b - 1,
+                new_index: a,
+            });
+        }
+        self.emit_batch(changes);
+    }
+
     pub fn clear(&mut self) {
         self.items.clear();
         self.changed_event.borrow().emit(VecDiff::Clear {});
@@ -62,6 +135,120 @@ impl<T: 'static + Clone> ObservableVec<T> {
             }
         }
     }
+
+    ///
+    /// Keeps only the items for which `f` returns true.
+    ///
+    /// All the removals are notified with a single batch event.
+    ///
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut changes = Vec::new();
+        let mut i = 0;
+        while i != self.items.len() {
+            if f(&self.items[i]) {
+                i += 1;
+            } else {
+                self.items.remove(i);
+                changes.push(VecDiff::RemoveAt { index: i });
+            }
+        }
+        self.emit_batch(changes);
+    }
+
+    ///
+    /// Appends all the items with a single batch event.
+    ///
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut changes = Vec::new();
+        for value in iter {
+            changes.push(VecDiff::InsertAt {
+                index: self.items.len(),
+                value: value.clone(),
+            });
+            self.items.push(value);
+        }
+        self.emit_batch(changes);
+    }
+
+    ///
+    /// Sorts the items (stable sort).
+    ///
+    /// The new order is notified as a batch of moves,
+    /// so no item is removed or recreated. Items which keep
+    /// their relative order are not moved.
+    ///
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        // items are keyed by their original indexes
+        let mut items: Vec<(usize, T)> = self.items.drain(..).enumerate().collect();
+        let mut sorted_items = items.clone();
+        sorted_items.sort_by(|(_, a), (_, b)| compare(a, b));
+
+        let changes = diff_keyed(&mut items, sorted_items, |_, _, _| false);
+
+        self.items = items.into_iter().map(|(_, value)| value).collect();
+        self.emit_batch(changes);
+    }
+
+    ///
+    /// Changes the content to `new_items` with minimal set of changes.
+    ///
+    /// Items are matched by the key returned by `key_fn`, so matched items
+    /// are only moved (their views are not recreated). Matched items
+    /// which are not equal are replaced. All the changes are notified
+    /// with a single batch event.
+    ///
+    /// Keys should be unique. If they are not, all the items are replaced.
+    ///
+    /// Example:
+    ///
+    /// let documents = load_documents()?;
+    /// vm.documents.reconcile(documents, |document| document.borrow().id);
+    ///
+    pub fn reconcile<K, F>(&mut self, new_items: Vec<T>, key_fn: F)
+    where
+        T: PartialEq,
+        K: Clone + Eq + Hash,
+        F: Fn(&T) -> K,
+    {
+        let mut items: Vec<(K, T)> = self
+            .items
+            .drain(..)
+            .map(|value| (key_fn(&value), value))
+            .collect();
+        let new_items = new_items
+            .into_iter()
+            .map(|value| (key_fn(&value), value))
+            .collect();
+
+        let changes = diff_keyed(&mut items, new_items, |_, old_value, new_value| {
+            old_value != new_value
+        });
+
+        self.items = items.into_iter().map(|(_, value)| value).collect();
+        self.emit_batch(changes);
+    }
+
+    ///
+    /// Applies the change and notifies about it with a single event.
+    ///
+    pub(crate) fn apply_change(&mut self, change: VecDiff<T>) {
+        change.clone().apply_to(&mut self.items);
+        self.changed_event.borrow().emit(change);
+    }
+
+    fn emit_batch(&self, mut changes: Vec<VecDiff<T>>) {
+        match changes.len() {
+            0 => (),
+            1 => self.changed_event.borrow().emit(changes.remove(0)),
+            _ => self.changed_event.borrow().emit(VecDiff::Batch { changes }),
+        }
+    }
 }
 
 impl<'a, T: 'static + Clone> IntoIterator for &'a ObservableVec<T> {
@@ -0,0 +1,308 @@
+use serde::de::{Deserialize, DeserializeOwned, DeserializeSeed, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::cell::{Cell, RefCell};
+use std::hash::Hash;
+use std::rc::{Rc, Weak};
+
+use crate::{
+    spawn_local_and_forget, ObservableMap, ObservableTree, ObservableVec, Property,
+    ReadOnlyProperty, Subscription, TreeNode, VecDiff,
+};
+
+///
+/// State of the view model that can be saved and loaded in place.
+///
+/// Loading sets values of the existing observables (instead of creating new ones),
+/// so bindings and subscriptions keep working after the state is restored.
+///
+/// It is implemented for the observables and can be derived for view models
+/// with `#[derive(PersistentState)]` from `fui_macros`. Fields marked with `#[state(skip)]`
+/// are not saved nor tracked. Fields missing in the loaded state keep their current values
+/// and unknown fields are ignored, so the state of the previous version can be loaded.
+///
+/// Example:
+///
+/// #[derive(PersistentState)]
+/// pub struct MainViewModel {
+///     pub name: Property<String>,
+///     pub selected_tab: Property<usize>,
+///     pub recent_files: ObservableVec<String>,
+///     #[state(skip)]
+///     pub save_command: Command,
+/// }
+///
+/// let json = serde_json::to_string(&SavedState(&*vm.borrow()))?;
+/// LoadedState(&mut *vm.borrow_mut())
+///     .deserialize(&mut serde_json::Deserializer::from_str(&json))?;
+///
+pub trait PersistentState {
+    fn save_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn load_state<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error>;
+
+    ///
+    /// Reports changes of the state (made after this call) until the subscription is dropped.
+    ///
+    fn track_changes(&self, changes: &StateChanges) -> Subscription;
+}
+
+///
+/// Serializes the state with any serde serializer.
+///
+pub struct SavedState<'a, X>(pub &'a X);
+
+impl<'a, X: PersistentState> Serialize for SavedState<'a, X> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.save_state(serializer)
+    }
+}
+
+///
+/// Loads the state in place with any serde deserializer.
+///
+pub struct LoadedState<'a, X>(pub &'a mut X);
+
+impl<'de, 'a, X: PersistentState> DeserializeSeed<'de> for LoadedState<'a, X> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.0.load_state(deserializer)
+    }
+}
+
+///
+/// Tells whether the state has changed since it was last saved or loaded.
+///
+/// Example:
+///
+/// let tracker = ChangeTracker::new(&*vm.borrow());
+/// let subscription = tracker.is_dirty().on_changed(move |is_dirty| {
+///     window.set_title(&format!("Document{}", if is_dirty { " *" } else { "" }));
+/// });
+///
+/// save(&vm);
+/// tracker.mark_clean();
+///
+pub struct ChangeTracker {
+    inner: Rc<ChangeTrackerInner>,
+    _subscription: Subscription,
+}
+
+struct ChangeTrackerInner {
+    is_dirty: Property<bool>,
+    mark_clean_handlers: RefCell<Vec<Box<dyn Fn()>>>,
+}
+
+impl ChangeTracker {
+    pub fn new<X: PersistentState>(state: &X) -> Self {
+        let inner = Rc::new(ChangeTrackerInner {
+            is_dirty: Property::new(false),
+            mark_clean_handlers: RefCell::new(Vec::new()),
+        });
+        let subscription = state.track_changes(&StateChanges {
+            inner: Rc::downgrade(&inner),
+        });
+        ChangeTracker {
+            inner,
+            _subscription: subscription,
+        }
+    }
+
+    pub fn is_dirty(&self) -> ReadOnlyProperty<bool> {
+        self.inner.is_dirty.read_only()
+    }
+
+    ///
+    /// Marks the current state as the saved one.
+    ///
+    /// Should be called after the state is saved or loaded.
+    ///
+    pub fn mark_clean(&self) {
+        for handler in self.inner.mark_clean_handlers.borrow().iter() {
+            handler();
+        }
+        self.inner.is_dirty.clone().set(false);
+    }
+}
+
+///
+/// Receiver of the state changes passed to `PersistentState::track_changes()`.
+///
+#[derive(Clone)]
+pub struct StateChanges {
+    inner: Weak<ChangeTrackerInner>,
+}
+
+impl StateChanges {
+    ///
+    /// Marks the state as changed.
+    ///
+    pub fn changed(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.is_dirty.clone().set(true);
+        }
+    }
+
+    ///
+    /// Registers handler called when the current state is marked as the saved one.
+    ///
+    pub fn on_mark_clean<F: 'static + Fn()>(&self, f: F) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.mark_clean_handlers.borrow_mut().push(Box::new(f));
+        }
+    }
+
+    ///
+    /// Returns handler of the collection change notifications that marks the state as changed.
+    ///
+    /// Notifications are queued, so the ones of the changes made before `mark_clean()`
+    /// (like loading the state) can be delivered after it. They are ignored
+    /// until the marker queued by `mark_clean()` is reached.
+    ///
+    fn collection_changed_handler(&self) -> impl 'static + Fn() {
+        // generation of the last mark_clean() and of the last marker reached
+        let marked_generation = Rc::new(Cell::new(0usize));
+        let reached_generation = Rc::new(Cell::new(0usize));
+
+        self.on_mark_clean({
+            let marked_generation = marked_generation.clone();
+            let reached_generation = reached_generation.clone();
+            move || {
+                let generation = marked_generation.get() + 1;
+                marked_generation.set(generation);
+                let reached_generation = reached_generation.clone();
+                spawn_local_and_forget(async move { reached_generation.set(generation) });
+            }
+        });
+
+        let changes = self.clone();
+        move || {
+            if reached_generation.get() == marked_generation.get() {
+                changes.changed();
+            }
+        }
+    }
+}
+
+impl<T: 'static + Clone + PartialEq + Serialize + DeserializeOwned> PersistentState
+    for Property<T>
+{
+    fn save_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    fn load_state<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error> {
+        self.set(T::deserialize(deserializer)?);
+        Ok(())
+    }
+
+    fn track_changes(&self, changes: &StateChanges) -> Subscription {
+        // values are notified asynchronously, so they are compared
+        // with the saved value instead of just reporting every notification
+        let saved_value = Rc::new(RefCell::new(self.get()));
+        changes.on_mark_clean({
+            let property = self.clone();
+            let saved_value = saved_value.clone();
+            move || *saved_value.borrow_mut() = property.get()
+        });
+
+        let changes = changes.clone();
+        self.on_changed(move |value| {
+            let is_changed = value != *saved_value.borrow();
+            if is_changed {
+                changes.changed();
+            }
+        })
+    }
+}
+
+impl<T: 'static + Clone + Serialize + DeserializeOwned> PersistentState for ObservableVec<T> {
+    fn save_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    fn load_state<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error> {
+        let items = Vec::<T>::deserialize(deserializer)?;
+
+        let mut changes = Vec::new();
+        if self.len() > 0 {
+            changes.push(VecDiff::Clear {});
+        }
+        changes.extend(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| VecDiff::InsertAt { index, value }),
+        );
+        if !changes.is_empty() {
+            self.apply_change(VecDiff::Batch { changes });
+        }
+        Ok(())
+    }
+
+    fn track_changes(&self, changes: &StateChanges) -> Subscription {
+        let changed = changes.collection_changed_handler();
+        Subscription::EventSubscription(self.on_changed(move |_| changed()))
+    }
+}
+
+impl<K, V> PersistentState for ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash + Serialize + DeserializeOwned,
+    V: 'static + Clone + Serialize + DeserializeOwned,
+{
+    fn save_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    fn load_state<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error> {
+        let pairs = Vec::<(K, V)>::deserialize(deserializer)?;
+        self.clear();
+        for (key, value) in pairs {
+            self.insert(key, value);
+        }
+        Ok(())
+    }
+
+    fn track_changes(&self, changes: &StateChanges) -> Subscription {
+        let changed = changes.collection_changed_handler();
+        Subscription::EventSubscription(self.on_changed(move |_| changed()))
+    }
+}
+
+impl<T: 'static + Clone + Serialize + DeserializeOwned> PersistentState for ObservableTree<T> {
+    fn save_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    fn load_state<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error> {
+        let roots = Vec::<TreeNode<T>>::deserialize(deserializer)?;
+        self.clear();
+        for node in roots {
+            self.push(&[], node);
+        }
+        Ok(())
+    }
+
+    fn track_changes(&self, changes: &StateChanges) -> Subscription {
+        let changed = changes.collection_changed_handler();
+        Subscription::EventSubscription(self.on_changed(move |_| changed()))
+    }
+}
+
+///
+/// Nested view models are saved and loaded in place.
+///
+impl<X: PersistentState> PersistentState for Rc<RefCell<X>> {
+    fn save_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.borrow().save_state(serializer)
+    }
+
+    fn load_state<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error> {
+        self.borrow_mut().load_state(deserializer)
+    }
+
+    fn track_changes(&self, changes: &StateChanges) -> Subscription {
+        self.borrow().track_changes(changes)
+    }
+}
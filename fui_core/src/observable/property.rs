@@ -1,12 +1,17 @@
-use futures_signals::signal::{Mutable, SignalExt};
+use futures_signals::map_ref;
+use futures_signals::signal::{Mutable, Signal, SignalExt};
+use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
+use crate::observable::validation::Validation;
+use crate::task::synchronous_executor::SynchronousExecutor;
 use crate::ObservableCollection;
-use crate::{spawn_local, Color, Subscription, VecDiff};
+use crate::{spawn_local, Alignment, Color, ReadOnlyProperty, Subscription, Thickness, VecDiff};
 
 pub struct Property<T> {
-    data: Mutable<T>,
-    bind_handle: Arc<RwLock<Option<Subscription>>>,
+    pub(crate) data: Mutable<T>,
+    pub(crate) bind_handle: Arc<RwLock<Option<Subscription>>>,
+    pub(crate) validation: Arc<RwLock<Option<Validation<T>>>>,
 }
 
 impl<T: 'static + Clone + PartialEq> Property<T> {
@@ -14,18 +19,22 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         Property {
             data: Mutable::new(val.into()),
             bind_handle: Arc::new(RwLock::new(None)),
+            validation: Arc::new(RwLock::new(None)),
         }
     }
 
+    #[track_caller]
     pub fn binded_from(src_property: &Property<T>) -> Self {
         let mut new_property = Property {
             data: Mutable::new(src_property.get()),
             bind_handle: Arc::new(RwLock::new(None)),
+            validation: Arc::new(RwLock::new(None)),
         };
         new_property.bind(src_property);
         new_property
     }
 
+    #[track_caller]
     pub fn binded_c_from<TSrc: 'static + Clone + PartialEq, F: 'static + Fn(TSrc) -> T>(
         src_property: &Property<TSrc>,
         f: F,
@@ -35,12 +44,141 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         new_property
     }
 
+    ///
+    /// Creates property computed from two source properties.
+    ///
+    /// The value is recalculated every time any of the sources changes.
+    /// The bindings are dropped together with the last clone of the returned property.
+    /// The result is read only, so the computation cannot be overwritten by a set or a binding.
+    ///
+    /// Example:
+    ///
+    /// let full_name = Property::computed2(&vm.first_name, &vm.last_name,
+    ///     |first, last| format!("{} {}", first, last));
+    ///
+    #[track_caller]
+    pub fn computed2<T1, T2, F>(
+        src1: &Property<T1>,
+        src2: &Property<T2>,
+        f: F,
+    ) -> ReadOnlyProperty<T>
+    where
+        T1: 'static + Clone + PartialEq,
+        T2: 'static + Clone + PartialEq,
+        F: 'static + Fn(T1, T2) -> T,
+    {
+        let init_value = f(src1.get(), src2.get());
+        let signal = map_ref! {
+            let v1 = src1.data.signal_cloned(),
+            let v2 = src2.data.signal_cloned() =>
+            f(v1.clone(), v2.clone())
+        };
+        Property::computed_from_signal(init_value, signal).read_only()
+    }
+
+    ///
+    /// Creates property computed from three source properties.
+    ///
+    #[track_caller]
+    pub fn computed3<T1, T2, T3, F>(
+        src1: &Property<T1>,
+        src2: &Property<T2>,
+        src3: &Property<T3>,
+        f: F,
+    ) -> ReadOnlyProperty<T>
+    where
+        T1: 'static + Clone + PartialEq,
+        T2: 'static + Clone + PartialEq,
+        T3: 'static + Clone + PartialEq,
+        F: 'static + Fn(T1, T2, T3) -> T,
+    {
+        let init_value = f(src1.get(), src2.get(), src3.get());
+        let signal = map_ref! {
+            let v1 = src1.data.signal_cloned(),
+            let v2 = src2.data.signal_cloned(),
+            let v3 = src3.data.signal_cloned() =>
+            f(v1.clone(), v2.clone(), v3.clone())
+        };
+        Property::computed_from_signal(init_value, signal).read_only()
+    }
+
+    ///
+    /// Creates property computed from any number of source properties of the same type.
+    ///
+    /// Example:
+    ///
+    /// let can_submit = Property::computed_n(&[&vm.is_valid, &vm.is_not_busy],
+    ///     |values| values.iter().all(|v| *v));
+    ///
+    #[track_caller]
+    pub fn computed_n<TSrc, F>(src_properties: &[&Property<TSrc>], f: F) -> ReadOnlyProperty<T>
+    where
+        TSrc: 'static + Clone + PartialEq,
+        F: 'static + Fn(Vec<TSrc>) -> T,
+    {
+        let sources: Vec<Mutable<TSrc>> = src_properties
+            .iter()
+            .map(|property| property.data.clone())
+            .collect();
+        let get_values = {
+            let sources = sources.clone();
+            move || sources.iter().map(|data| data.get_cloned()).collect()
+        };
+
+        let new_property = Property::new(f(get_values()));
+
+        let f = Rc::new(f);
+        let get_values = Rc::new(get_values);
+        let mut handles = Vec::new();
+        for src_data in &sources {
+            let data = new_property.data.clone();
+            let f = f.clone();
+            let get_values = get_values.clone();
+            handles.push(Subscription::SpawnLocal(spawn_local(
+                src_data.signal_cloned().for_each(move |_| {
+                    data.set_neq(f(get_values()));
+                    async {}
+                }),
+            )));
+        }
+        new_property
+            .bind_handle
+            .write()
+            .unwrap()
+            .replace(Subscription::Many(handles));
+
+        new_property.read_only()
+    }
+
+    #[track_caller]
+    pub(crate) fn computed_from_signal<S>(init_value: T, signal: S) -> Self
+    where
+        S: 'static + Signal<Item = T>,
+    {
+        let new_property = Property::new(init_value);
+        let handle = spawn_local(signal.for_each({
+            let data = new_property.data.clone();
+            move |v| {
+                data.set_neq(v);
+                async {}
+            }
+        }));
+        new_property
+            .bind_handle
+            .write()
+            .unwrap()
+            .replace(Subscription::SpawnLocal(handle));
+        new_property
+    }
+
+    #[track_caller]
     pub fn binded_to(dst_property: &mut Property<T>, init_value: T) -> Self {
         let property = Property::new(init_value);
         dst_property.bind(&property);
         property
     }
 
+    #[track_caller]
     pub fn binded_c_to<TDst: 'static + Clone + PartialEq, F: 'static + Fn(T) -> TDst>(
         dst_property: &mut Property<TDst>,
         f: F,
@@ -55,6 +193,7 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         other_property.clone()
     }
 
+    #[track_caller]
     pub fn binded_c_two_way<TOther, F1, F2>(
         other_property: &mut Property<TOther>,
         f1: F1,
@@ -70,19 +209,46 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         property
     }
 
+    ///
+    /// Two-way binding with converter that can fail.
+    ///
+    /// Conversion errors are reported as validation errors of the `other_property`.
+    /// The returned property shares validation errors with the `other_property`.
+    ///
+    #[track_caller]
+    pub fn binded_c_try_two_way<TOther, F1, F2, E>(
+        other_property: &mut Property<TOther>,
+        f1: F1,
+        f2: F2,
+    ) -> Self
+    where
+        TOther: 'static + Clone + PartialEq,
+        F1: 'static + Fn(TOther) -> T,
+        F2: 'static + Fn(T) -> Result<TOther, E>,
+        E: ToString,
+    {
+        let mut property = Property::binded_c_from(other_property, f1);
+        property.share_errors_with(other_property);
+        other_property.bind_c_try(&mut property, f2);
+        property
+    }
+
     pub fn set(&mut self, val: T) {
         self.data.set_neq(val);
+        SynchronousExecutor::flush_if_enabled();
     }
 
     pub fn change<F: 'static + Fn(T) -> T>(&mut self, f: F) {
         let val = self.data.get_cloned();
         self.data.set_neq(f(val));
+        SynchronousExecutor::flush_if_enabled();
     }
 
     pub fn get(&self) -> T {
         self.data.get_cloned()
     }
 
+    #[track_caller]
     pub fn bind(&mut self, src_property: &Property<T>) {
         let handle = spawn_local(src_property.data.signal_cloned().for_each({
             let data = self.data.clone();
@@ -97,6 +263,7 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
             .replace(Subscription::SpawnLocal(handle));
     }
 
+    #[track_caller]
     pub fn bind_c<TSrc: 'static + Clone + PartialEq, F: 'static + Fn(TSrc) -> T>(
         &mut self,
         src_property: &Property<TSrc>,
@@ -115,6 +282,41 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
             .replace(Subscription::SpawnLocal(handle));
     }
 
+    ///
+    /// Binding with converter that can fail.
+    ///
+    /// When conversion fails the value is not changed
+    /// and the error is reported as a validation error.
+    ///
+    #[track_caller]
+    pub fn bind_c_try<TSrc, F, E>(&mut self, src_property: &Property<TSrc>, f: F)
+    where
+        TSrc: 'static + Clone + PartialEq,
+        F: 'static + Fn(TSrc) -> Result<T, E>,
+        E: ToString,
+    {
+        let handle = spawn_local(src_property.data.signal_cloned().for_each({
+            let data = self.data.clone();
+            let validation = Arc::downgrade(&self.validation);
+            move |v| {
+                let conversion_error = match f(v) {
+                    Ok(v) => {
+                        data.set_neq(v);
+                        None
+                    }
+                    Err(err) => Some(err.to_string()),
+                };
+                Validation::set_conversion_error(&validation, conversion_error);
+                async {}
+            }
+        }));
+        self.bind_handle
+            .write()
+            .unwrap()
+            .replace(Subscription::SpawnLocal(handle));
+    }
+
+    #[track_caller]
     pub fn on_changed<F: 'static + FnMut(T)>(&self, mut f: F) -> Subscription {
         Subscription::SpawnLocal(spawn_local(self.data.signal_cloned().for_each(move |v| {
             f(v);
@@ -128,12 +330,14 @@ impl<T: 'static + Clone + PartialEq> Clone for Property<T> {
         Property::<T> {
             data: self.data.clone(),
             bind_handle: self.bind_handle.clone(),
+            validation: self.validation.clone(),
         }
     }
 
     fn clone_from(&mut self, source: &Self) {
         self.data.clone_from(&source.data);
         self.bind_handle.clone_from(&source.bind_handle);
+        self.validation.clone_from(&source.validation);
     }
 }
 
@@ -163,6 +367,9 @@ impl IntoProperty for usize {}
 impl IntoProperty for f32 {}
 impl IntoProperty for f64 {}
 impl IntoProperty for Color {}
+impl IntoProperty for Thickness {}
+impl IntoProperty for Alignment {}
+impl<T> IntoProperty for Vec<T> {}
 
 ///
 /// Allows to convert types attributed with IntoProperty to Property<T>.
@@ -254,6 +461,40 @@ where
     }
 }
 
+///
+/// Marks converter that can fail.
+///
+/// Used to write two-way bindings with converters that report
+/// validation errors instead of panicking.
+///
+/// Example:
+///
+/// ui! { TextBox { text: (&mut vm.count,
+///     |c| c.to_string(), TryConvert(|s: String| s.parse::<i32>())) }}
+///
+pub struct TryConvert<F>(pub F);
+
+///
+/// Allows to easily write two-way binding with converter that can fail.
+///
+/// Example:
+///
+/// ui! { Control { text_property: (&mut vm.count,
+///     |c| c.to_string(), TryConvert(|c: String| c.parse::<i32>())) }}
+///
+impl<TSrc, TDest, F1, F2, E> From<(&mut Property<TSrc>, F1, TryConvert<F2>)> for Property<TDest>
+where
+    TSrc: 'static + Clone + PartialEq,
+    TDest: 'static + Clone + PartialEq,
+    F1: 'static + Fn(TSrc) -> TDest,
+    F2: 'static + Fn(TDest) -> Result<TSrc, E>,
+    E: ToString,
+{
+    fn from(value: (&mut Property<TSrc>, F1, TryConvert<F2>)) -> Property<TDest> {
+        Property::binded_c_try_two_way(value.0, value.1, (value.2).0)
+    }
+}
+
 ///
 /// ObservableCollection for Property.
 ///
@@ -275,8 +516,7 @@ where
 
     fn on_changed(&self, mut f: Box<dyn FnMut(VecDiff<T>)>) -> Option<Subscription> {
         Some(Property::on_changed(self, move |v| {
-            f(VecDiff::RemoveAt { index: 0 });
-            f(VecDiff::InsertAt { index: 0, value: v });
+            f(VecDiff::Replace { index: 0, value: v });
         }))
     }
 }
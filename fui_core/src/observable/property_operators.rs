@@ -0,0 +1,182 @@
+use futures_signals::map_ref;
+use futures_signals::signal::{Mutable, SignalExt};
+use futures_util::StreamExt;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{timeout, timeout_at, Instant};
+
+use crate::{spawn_local, Property, Subscription};
+
+///
+/// Operators creating new properties derived from the source property.
+///
+/// The returned property is updated by the task spawned with `spawn_local()`.
+/// The task is aborted when the last clone of the returned property is dropped.
+///
+/// Time based operators (`debounce`, `throttle`) use tokio timers,
+/// so they need tokio runtime also in the synchronous mode.
+///
+impl<T: 'static + Clone + PartialEq> Property<T> {
+    ///
+    /// Property updated with the source value after the source
+    /// has not changed for the given time.
+    ///
+    /// Example:
+    ///
+    /// let search_text = vm.text.debounce(Duration::from_millis(300));
+    /// let subscription = search_text.on_changed(move |text| run_query(text));
+    ///
+    pub fn debounce(&self, duration: Duration) -> Property<T> {
+        let source = self.data.clone();
+        Property::from_task(self.get(), move |data| async move {
+            let mut values = source.signal_cloned().to_stream();
+
+            // skip the current value
+            values.next().await;
+
+            while let Some(mut value) = values.next().await {
+                // wait until the value stops changing
+                while let Ok(Some(new_value)) = timeout(duration, values.next()).await {
+                    value = new_value;
+                }
+                data.set_neq(value);
+            }
+        })
+    }
+
+    ///
+    /// Property updated with the source value at most once per the given time.
+    ///
+    /// The first change is propagated immediately, the last change
+    /// made during the waiting time is propagated when the time elapses.
+    ///
+    pub fn throttle(&self, duration: Duration) -> Property<T> {
+        let source = self.data.clone();
+        Property::from_task(self.get(), move |data| async move {
+            let mut values = source.signal_cloned().to_stream();
+
+            // skip the current value
+            values.next().await;
+
+            let mut pending_value = None;
+            loop {
+                let value = match pending_value.take() {
+                    Some(value) => value,
+                    None => match values.next().await {
+                        Some(value) => value,
+                        None => return,
+                    },
+                };
+                data.set_neq(value);
+
+                let deadline = Instant::now() + duration;
+                loop {
+                    match timeout_at(deadline, values.next()).await {
+                        Ok(Some(value)) => pending_value = Some(value),
+                        Ok(None) => {
+                            if let Some(value) = pending_value.take() {
+                                data.set_neq(value);
+                            }
+                            return;
+                        }
+                        Err(_) => break,
+                    }
+                }
+            }
+        })
+    }
+
+    ///
+    /// Property updated only with the source values matching the predicate.
+    ///
+    /// The initial value is the current value of the source
+    /// (even if it does not match the predicate).
+    ///
+    pub fn filter<F: 'static + Fn(&T) -> bool>(&self, f: F) -> Property<T> {
+        let source = self.data.clone();
+        Property::from_task(self.get(), move |data| {
+            source.signal_cloned().for_each(move |value| {
+                if f(&value) {
+                    data.set_neq(value);
+                }
+                async {}
+            })
+        })
+    }
+
+    ///
+    /// Property updated only when the comparer reports that
+    /// the source value is different from the last propagated value.
+    ///
+    /// Example:
+    ///
+    /// let name = vm.name.distinct_until_changed_by(|a, b| a.trim() == b.trim());
+    ///
+    pub fn distinct_until_changed_by<F: 'static + Fn(&T, &T) -> bool>(&self, f: F) -> Property<T> {
+        let source = self.data.clone();
+        Property::from_task(self.get(), move |data| {
+            source.signal_cloned().for_each(move |value| {
+                let is_equal = f(&data.lock_ref(), &value);
+                if !is_equal {
+                    data.set(value);
+                }
+                async {}
+            })
+        })
+    }
+
+    ///
+    /// Property holding the latest values of both properties.
+    ///
+    pub fn zip<U: 'static + Clone + PartialEq>(&self, other: &Property<U>) -> Property<(T, U)> {
+        let init_value = (self.get(), other.get());
+        let signal = map_ref! {
+            let value = self.data.signal_cloned(),
+            let other_value = other.data.signal_cloned() =>
+            (value.clone(), other_value.clone())
+        };
+        Property::computed_from_signal(init_value, signal)
+    }
+
+    ///
+    /// Property updated with the current source value every time the trigger changes.
+    ///
+    /// Example:
+    ///
+    /// // take the text when the refresh counter is increased
+    /// let query = vm.text.sample(&vm.refresh_counter);
+    ///
+    pub fn sample<U: 'static + Clone + PartialEq>(&self, trigger: &Property<U>) -> Property<T> {
+        let source = self.data.clone();
+        let trigger = trigger.data.clone();
+        Property::from_task(self.get(), move |data| async move {
+            let mut trigger_values = trigger.signal_cloned().to_stream();
+
+            // skip the current value
+            trigger_values.next().await;
+
+            while trigger_values.next().await.is_some() {
+                data.set_neq(source.get_cloned());
+            }
+        })
+    }
+
+    ///
+    /// Creates property updated by the task.
+    ///
+    #[track_caller]
+    pub(crate) fn from_task<F, Fut>(init_value: T, f: F) -> Property<T>
+    where
+        F: FnOnce(Mutable<T>) -> Fut,
+        Fut: 'static + Future<Output = ()>,
+    {
+        let new_property = Property::new(init_value);
+        let handle = spawn_local(f(new_property.data.clone()));
+        new_property
+            .bind_handle
+            .write()
+            .unwrap()
+            .replace(Subscription::SpawnLocal(handle));
+        new_property
+    }
+}
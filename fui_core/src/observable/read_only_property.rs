@@ -0,0 +1,121 @@
+use crate::{Property, Subscription};
+
+///
+/// Read only view of the Property<T>.
+///
+/// Allows to expose view model's outputs. The value can be read,
+/// observed and used as a source of one-way bindings, but cannot be set.
+///
+/// Example:
+///
+/// pub struct MainViewModel {
+///     counter: Property<i32>,
+///     pub counter_output: ReadOnlyProperty<i32>,
+/// }
+///
+/// let counter = Property::new(0);
+/// let counter_output = counter.read_only();
+///
+/// ui! { Text { text: (&vm.counter_output, |c| c.to_string()) }}
+///
+/// Two-way bindings to read only properties are rejected by the compiler:
+///
+/// ```compile_fail
+/// use fui_core::{Property, ReadOnlyProperty};
+///
+/// let source = Property::new(10);
+/// let mut read_only: ReadOnlyProperty<i32> = source.read_only();
+/// let two_way: Property<i32> = (&mut read_only).into();
+/// ```
+///
+pub struct ReadOnlyProperty<T> {
+    property: Property<T>,
+}
+
+impl<T: 'static + Clone + PartialEq> ReadOnlyProperty<T> {
+    pub fn new(property: Property<T>) -> Self {
+        ReadOnlyProperty { property }
+    }
+
+    pub fn get(&self) -> T {
+        self.property.get()
+    }
+
+    #[track_caller]
+    pub fn on_changed<F: 'static + FnMut(T)>(&self, f: F) -> Subscription {
+        self.property.on_changed(f)
+    }
+}
+
+impl<T: 'static + Clone + PartialEq> Property<T> {
+    ///
+    /// Returns read only view of the property.
+    ///
+    /// The returned property shares the value with this property.
+    ///
+    pub fn read_only(&self) -> ReadOnlyProperty<T> {
+        ReadOnlyProperty::new(self.clone())
+    }
+
+    #[track_caller]
+    pub fn bind_read_only(&mut self, src_property: &ReadOnlyProperty<T>) {
+        self.bind(&src_property.property);
+    }
+
+    #[track_caller]
+    pub fn bind_c_read_only<TSrc: 'static + Clone + PartialEq, F: 'static + Fn(TSrc) -> T>(
+        &mut self,
+        src_property: &ReadOnlyProperty<TSrc>,
+        f: F,
+    ) {
+        self.bind_c(&src_property.property, f);
+    }
+}
+
+impl<T: 'static + Clone + PartialEq> Clone for ReadOnlyProperty<T> {
+    fn clone(&self) -> Self {
+        ReadOnlyProperty {
+            property: self.property.clone(),
+        }
+    }
+}
+
+impl<T: 'static + Clone + PartialEq> From<Property<T>> for ReadOnlyProperty<T> {
+    fn from(property: Property<T>) -> ReadOnlyProperty<T> {
+        ReadOnlyProperty::new(property)
+    }
+}
+
+///
+/// Allows to easily write one-way binding.
+///
+/// Example:
+///
+/// ui! { Control { text_property: &vm.read_only_text }}
+///
+impl<T> From<&ReadOnlyProperty<T>> for Property<T>
+where
+    T: 'static + Clone + PartialEq,
+{
+    fn from(value: &ReadOnlyProperty<T>) -> Property<T> {
+        Property::binded_from(&value.property)
+    }
+}
+
+///
+/// Allows to easily write one-way binding with converter.
+///
+/// Example:
+///
+/// ui! { Control { text_property: (&vm.read_only_count, |c| c.to_string()) }}
+///
+impl<TSrc, TDest, F> From<(&ReadOnlyProperty<TSrc>, F)> for Property<TDest>
+where
+    TSrc: 'static + Clone + PartialEq,
+    TDest: 'static + Clone + PartialEq,
+    F: 'static + Fn(TSrc) -> TDest,
+{
+    fn from(value: (&ReadOnlyProperty<TSrc>, F)) -> Property<TDest> {
+        Property::binded_c_from(&value.0.property, value.1)
+    }
+}
@@ -0,0 +1,227 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use crate::{Dispatcher, ObservableVec, Property};
+
+type TargetFn<X> = Box<dyn FnOnce(&mut X)>;
+type Accessor<X> = Rc<dyn Fn(TargetFn<X>)>;
+
+thread_local! {
+    // objects of the VM thread accessible by remote handles (by target id)
+    static REMOTE_TARGETS: RefCell<HashMap<usize, Box<dyn Any>>> = RefCell::new(HashMap::new());
+    static NEXT_TARGET_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+///
+/// Object of the VM thread that can be modified from other threads.
+///
+/// The object is unregistered when the last remote handle is dropped.
+///
+struct RemoteTarget<X> {
+    id: usize,
+    dispatcher: Dispatcher,
+    _phantom: PhantomData<fn(&mut X)>,
+}
+
+impl<X: 'static> RemoteTarget<X> {
+    fn register(accessor: Accessor<X>) -> Self {
+        let id = NEXT_TARGET_ID.with(|next_id| {
+            let id = next_id.get();
+            next_id.set(id + 1);
+            id
+        });
+        REMOTE_TARGETS.with(|targets| {
+            targets.borrow_mut().insert(id, Box::new(accessor));
+        });
+
+        RemoteTarget {
+            id,
+            dispatcher: Dispatcher::current(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn post<F: 'static + Send + FnOnce(&mut X)>(&self, f: F) {
+        let id = self.id;
+        self.dispatcher.post(move || {
+            // accessor is cloned, so targets are not borrowed when it is called
+            let accessor = REMOTE_TARGETS.with(|targets| {
+                targets
+                    .borrow()
+                    .get(&id)
+                    .and_then(|accessor| accessor.downcast_ref::<Accessor<X>>())
+                    .cloned()
+            });
+            if let Some(accessor) = accessor {
+                accessor(Box::new(f));
+            }
+        });
+    }
+}
+
+impl<X> Drop for RemoteTarget<X> {
+    fn drop(&mut self) {
+        let id = self.id;
+        self.dispatcher.post(move || {
+            let accessor = REMOTE_TARGETS.with(|targets| targets.borrow_mut().remove(&id));
+
+            // dropped when the targets are not borrowed
+            drop(accessor);
+        });
+    }
+}
+
+///
+/// Handle to set the property value from any thread.
+///
+/// Values are set on the VM thread (by the application's message loop).
+/// Values set faster than the VM thread processes them are coalesced,
+/// so it can be used to report progress from tight loops.
+///
+/// Example:
+///
+/// let progress = vm.progress.sender();
+/// std::thread::spawn(move || {
+///     for i in 0..=100 {
+///         do_work(i);
+///         progress.set(i as f32 / 100.0f32);
+///     }
+/// });
+///
+pub struct PropertySender<T> {
+    target: Arc<RemoteTarget<Property<T>>>,
+    pending_value: Arc<Mutex<Option<T>>>,
+}
+
+impl<T: 'static + Clone + PartialEq + Send> Property<T> {
+    ///
+    /// Returns handle to set the property value from other threads.
+    ///
+    /// Must be called on the VM thread.
+    ///
+    pub fn sender(&self) -> PropertySender<T> {
+        let property = self.clone();
+        PropertySender {
+            target: Arc::new(RemoteTarget::register(Rc::new(
+                move |f: TargetFn<Property<T>>| f(&mut property.clone()),
+            ))),
+            pending_value: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl<T: 'static + Clone + PartialEq + Send> PropertySender<T> {
+    pub fn set(&self, value: T) {
+        let is_posted = self.pending_value.lock().unwrap().replace(value).is_some();
+        if !is_posted {
+            let pending_value = self.pending_value.clone();
+            self.target.post(move |property: &mut Property<T>| {
+                let value = pending_value.lock().unwrap().take();
+                if let Some(value) = value {
+                    property.set(value);
+                }
+            });
+        }
+    }
+}
+
+impl<T> Clone for PropertySender<T> {
+    fn clone(&self) -> Self {
+        PropertySender {
+            target: self.target.clone(),
+            pending_value: self.pending_value.clone(),
+        }
+    }
+}
+
+///
+/// Handle to modify the ObservableVec from any thread.
+///
+/// Changes are applied on the VM thread in the same order they are made.
+///
+/// Example:
+///
+/// let results = ObservableVec::remote(&vm_rc, |vm: &mut MainViewModel| &mut vm.results);
+/// tokio::spawn(async move {
+///     while let Some(item) = search.next().await {
+///         results.push(item);
+///     }
+/// });
+///
+pub struct ObservableVecRemote<T: 'static + Clone> {
+    target: Arc<RemoteTarget<ObservableVec<T>>>,
+}
+
+impl<T: 'static + Clone + Send> ObservableVec<T> {
+    ///
+    /// Returns handle to modify the vec owned by the view model from other threads.
+    ///
+    /// Changes made after the owner is dropped are ignored.
+    /// Must be called on the VM thread.
+    ///
+    pub fn remote<O, F>(owner: &Rc<RefCell<O>>, get_vec: F) -> ObservableVecRemote<T>
+    where
+        O: 'static,
+        F: 'static + Fn(&mut O) -> &mut ObservableVec<T>,
+    {
+        let owner = Rc::downgrade(owner);
+        ObservableVecRemote {
+            target: Arc::new(RemoteTarget::register(Rc::new(
+                move |f: TargetFn<ObservableVec<T>>| {
+                    if let Some(owner) = owner.upgrade() {
+                        f(get_vec(&mut owner.borrow_mut()));
+                    }
+                },
+            ))),
+        }
+    }
+}
+
+impl<T: 'static + Clone + Send> ObservableVecRemote<T> {
+    pub fn push(&self, value: T) {
+        self.update(move |vec| vec.push(value));
+    }
+
+    pub fn insert(&self, index: usize, value: T) {
+        self.update(move |vec| vec.insert(index, value));
+    }
+
+    pub fn remove_at(&self, index: usize) {
+        self.update(move |vec| {
+            vec.remove_at(index);
+        });
+    }
+
+    pub fn set(&self, index: usize, value: T) {
+        self.update(move |vec| {
+            vec.set(index, value);
+        });
+    }
+
+    pub fn extend(&self, values: Vec<T>) {
+        self.update(move |vec| vec.extend(values));
+    }
+
+    pub fn clear(&self) {
+        self.update(|vec| vec.clear());
+    }
+
+    ///
+    /// Runs any modification of the vec on the VM thread.
+    ///
+    pub fn update<F: 'static + Send + FnOnce(&mut ObservableVec<T>)>(&self, f: F) {
+        self.target.post(f);
+    }
+}
+
+impl<T: 'static + Clone> Clone for ObservableVecRemote<T> {
+    fn clone(&self) -> Self {
+        ObservableVecRemote {
+            target: self.target.clone(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::hash::Hash;
+
+use crate::{ObservableMap, ObservableTree, ObservableVec, Property, ReadOnlyProperty, TreeNode};
+
+//
+// Observables are serialized as their current values.
+// Bindings, subscriptions and validation are not serialized,
+// deserialized observables are not bound to anything.
+//
+
+impl<T: 'static + Clone + PartialEq + Serialize> Serialize for Property<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T: 'static + Clone + PartialEq + Deserialize<'de>> Deserialize<'de> for Property<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Property::new)
+    }
+}
+
+impl<T: 'static + Clone + PartialEq + Serialize> Serialize for ReadOnlyProperty<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<T: 'static + Clone + Serialize> Serialize for ObservableVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<'de, T: 'static + Clone + Deserialize<'de>> Deserialize<'de> for ObservableVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(|items| items.into_iter().collect())
+    }
+}
+
+///
+/// Serialized as a sequence of key-value pairs (in insertion order),
+/// so keys do not have to be strings.
+///
+impl<K, V> Serialize for ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash + Serialize,
+    V: 'static + Clone + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for pair in self.iter() {
+            seq.serialize_element(&pair)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for ObservableMap<K, V>
+where
+    K: 'static + Clone + Eq + Hash + Deserialize<'de>,
+    V: 'static + Clone + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<(K, V)>::deserialize(deserializer).map(|pairs| pairs.into_iter().collect())
+    }
+}
+
+///
+/// Serialized as a sequence of root nodes. Node ids are not serialized,
+/// new ids are assigned when the tree is deserialized.
+///
+impl<T: 'static + Clone + Serialize> Serialize for ObservableTree<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.roots())
+    }
+}
+
+impl<'de, T: 'static + Clone + Deserialize<'de>> Deserialize<'de> for ObservableTree<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let roots = Vec::<TreeNode<T>>::deserialize(deserializer)?;
+        let mut tree = ObservableTree::new();
+        for node in roots {
+            tree.push(&[], node);
+        }
+        Ok(tree)
+    }
+}
@@ -0,0 +1,151 @@
+use futures_signals::signal::{Signal, SignalExt};
+use futures_util::stream::Stream;
+use futures_util::StreamExt;
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+use crate::{spawn_local, ObservableCollection, ObservableVec, Property, Subscription, VecDiff};
+
+///
+/// Interoperability with `futures-signals` signals and `futures` streams.
+///
+impl<T: 'static + Clone + PartialEq> Property<T> {
+    ///
+    /// Signal of the property value.
+    ///
+    /// It can be used with the `futures-signals` combinators.
+    ///
+    pub fn signal(&self) -> impl Signal<Item = T> {
+        self.data.signal_cloned()
+    }
+
+    ///
+    /// Stream of the property values.
+    ///
+    /// The first item is the current value. Values set in between polls are coalesced,
+    /// so the stream always returns the latest value.
+    ///
+    pub fn to_stream(&self) -> impl Stream<Item = T> {
+        self.signal().to_stream()
+    }
+
+    ///
+    /// Creates property updated by the signal.
+    ///
+    /// The signal is polled until the last clone of the returned property is dropped.
+    ///
+    pub fn from_signal<S>(init_value: T, signal: S) -> Self
+    where
+        S: 'static + Signal<Item = T>,
+    {
+        Property::from_task(init_value, move |data| {
+            signal.for_each(move |value| {
+                data.set_neq(value);
+                async {}
+            })
+        })
+    }
+
+    ///
+    /// Creates property updated by the stream.
+    ///
+    /// Example:
+    ///
+    /// let status = Property::from_stream("Connecting...".to_string(), connection.status_stream());
+    ///
+    pub fn from_stream<S>(init_value: T, stream: S) -> Self
+    where
+        S: 'static + Stream<Item = T>,
+    {
+        Property::from_task(init_value, move |data| {
+            stream.for_each(move |value| {
+                data.set_neq(value);
+                async {}
+            })
+        })
+    }
+}
+
+impl<T: 'static + Clone> ObservableVec<T> {
+    ///
+    /// Applies changes from the stream to the vec owned by the view model.
+    ///
+    /// The stream is polled until the returned subscription is dropped
+    /// or the owner is dropped.
+    ///
+    /// Example:
+    ///
+    /// let subscription = ObservableVec::bind_stream(&vm_rc,
+    ///     |vm: &mut MainViewModel| &mut vm.search_results,
+    ///     indexer.results());
+    ///
+    pub fn bind_stream<O, F, S>(owner: &Rc<RefCell<O>>, get_vec: F, stream: S) -> Subscription
+    where
+        O: 'static,
+        F: 'static + Fn(&mut O) -> &mut ObservableVec<T>,
+        S: 'static + Stream<Item = VecDiff<T>>,
+    {
+        let owner = Rc::downgrade(owner);
+        Subscription::SpawnLocal(spawn_local(async move {
+            let mut stream = Box::pin(stream);
+            while let Some(change) = stream.next().await {
+                match owner.upgrade() {
+                    Some(owner) => get_vec(&mut owner.borrow_mut()).apply_change(change),
+                    None => break,
+                }
+            }
+        }))
+    }
+}
+
+///
+/// Stream of changes of the observable collection.
+///
+/// The first item inserts the current content of the collection
+/// (it is skipped when the collection is empty).
+/// The stream never ends by itself (the collection does not notify when it is dropped),
+/// so it should be dropped (or polled with a timeout) when it is no longer needed.
+///
+/// Example:
+///
+/// let changes = ObservableCollectionStream::new(&vm.items);
+///
+pub struct ObservableCollectionStream<T> {
+    receiver: mpsc::UnboundedReceiver<VecDiff<T>>,
+    _changed_subscription: Option<Subscription>,
+}
+
+impl<T: 'static + Clone> ObservableCollectionStream<T> {
+    pub fn new(source: &dyn ObservableCollection<T>) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let content: Vec<VecDiff<T>> = source
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| VecDiff::InsertAt { index, value })
+            .collect();
+        if !content.is_empty() {
+            let _ = sender.send(VecDiff::Batch { changes: content });
+        }
+
+        let changed_subscription = source.on_changed(Box::new(move |changed_args| {
+            let _ = sender.send(changed_args);
+        }));
+
+        ObservableCollectionStream {
+            receiver,
+            _changed_subscription: changed_subscription,
+        }
+    }
+}
+
+impl<T> Stream for ObservableCollectionStream<T> {
+    type Item = VecDiff<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
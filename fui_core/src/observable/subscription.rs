@@ -3,4 +3,7 @@ use crate::{EventSubscription, JoinHandle};
 pub enum Subscription {
     SpawnLocal(JoinHandle<()>),
     EventSubscription(EventSubscription),
+
+    /// Aggregation of subscriptions (for example when watching many properties).
+    Many(Vec<Subscription>),
 }
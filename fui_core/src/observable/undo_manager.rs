@@ -0,0 +1,592 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::{
+    spawn_local_and_forget, ObservableVec, Property, ReadOnlyProperty, Subscription, VecDiff,
+};
+
+///
+/// Records changes of the registered properties and collections
+/// and allows to undo and redo them.
+///
+/// Changes are recorded when the change notifications are delivered
+/// (the same way as bindings are updated) and pending property changes
+/// are recorded when the transaction begins or ends.
+/// Changes done by `undo()` and `redo()` are not recorded.
+///
+/// Consecutive changes of the same property done within the merge interval
+/// are merged into one step (for example typing in the text box).
+/// Changes done inside the transaction are undone as one step.
+///
+/// Register only the source properties. Properties updated by bindings
+/// would record their changes again when the source is undone.
+///
+/// Example:
+///
+/// let undo_manager = UndoManager::new();
+/// undo_manager.register_property(&vm.name);
+/// undo_manager.register_vec(&vm_rc, |vm: &mut MainViewModel| &mut vm.items);
+///
+/// undo_manager.transaction(|| {
+///     vm.name.set("New name".to_string());
+///     vm.position.set(10);
+/// });
+///
+/// let mut undo = Command::new_sync({
+///     let undo_manager = undo_manager.clone();
+///     move || { undo_manager.undo(); }
+/// });
+/// undo.can_execute.bind_read_only(&undo_manager.can_undo());
+///
+/// ui! { Button { command: undo, ... } }
+///
+#[derive(Clone)]
+pub struct UndoManager {
+    inner: Rc<RefCell<UndoManagerInner>>,
+}
+
+struct UndoManagerInner {
+    undo_steps: VecDeque<UndoStep>,
+    redo_steps: Vec<UndoStep>,
+
+    history_limit: usize,
+    merge_interval: Option<Duration>,
+
+    // depth of nested transactions for property changes (recorded immediately)
+    // and for collection changes (recorded when the queued notifications are delivered)
+    transaction_depth: usize,
+    queued_transaction_depth: usize,
+    transaction_step: Option<UndoStep>,
+
+    // false when the last step cannot be extended with merged changes
+    is_merge_allowed: bool,
+
+    next_target_id: usize,
+    subscriptions: Vec<Subscription>,
+    property_pollers: Vec<Rc<dyn Fn()>>,
+
+    can_undo: Property<bool>,
+    can_redo: Property<bool>,
+}
+
+struct PropertyRecorder<T> {
+    last_value: T,
+
+    // old and new value of the last recorded change (shared with undo and redo actions)
+    last_change: Option<Rc<RefCell<(T, T)>>>,
+}
+
+struct UndoStep {
+    changes: Vec<UndoChange>,
+}
+
+struct UndoChange {
+    target_id: usize,
+    time: Instant,
+    undo: Box<dyn Fn()>,
+    redo: Box<dyn Fn()>,
+}
+
+impl UndoManager {
+    pub fn new() -> Self {
+        UndoManager {
+            inner: Rc::new(RefCell::new(UndoManagerInner {
+                undo_steps: VecDeque::new(),
+                redo_steps: Vec::new(),
+                history_limit: 100,
+                merge_interval: Some(Duration::from_millis(1000)),
+                transaction_depth: 0,
+                queued_transaction_depth: 0,
+                transaction_step: None,
+                is_merge_allowed: false,
+                next_target_id: 0,
+                subscriptions: Vec::new(),
+                property_pollers: Vec::new(),
+                can_undo: Property::new(false),
+                can_redo: Property::new(false),
+            })),
+        }
+    }
+
+    ///
+    /// Sets maximum number of undo steps (100 by default).
+    ///
+    pub fn set_history_limit(&self, history_limit: usize) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.history_limit = history_limit;
+            inner.apply_history_limit();
+        }
+        UndoManagerInner::update_can_undo_redo(&self.inner);
+    }
+
+    ///
+    /// Sets the time in which consecutive changes of the same property
+    /// are merged into one step (1 second by default).
+    ///
+    /// None disables merging.
+    ///
+    pub fn set_merge_interval(&self, merge_interval: Option<Duration>) {
+        self.inner.borrow_mut().merge_interval = merge_interval;
+    }
+
+    pub fn can_undo(&self) -> ReadOnlyProperty<bool> {
+        self.inner.borrow().can_undo.read_only()
+    }
+
+    pub fn can_redo(&self) -> ReadOnlyProperty<bool> {
+        self.inner.borrow().can_redo.read_only()
+    }
+
+    ///
+    /// Records changes of the property.
+    ///
+    pub fn register_property<T>(&self, property: &Property<T>)
+    where
+        T: 'static + Clone + PartialEq,
+    {
+        let target_id = self.inner.borrow_mut().new_target_id();
+        let recorder = Rc::new(RefCell::new(PropertyRecorder {
+            last_value: property.get(),
+            last_change: None,
+        }));
+
+        // records the change if the value differs from the last known one
+        let poll: Rc<dyn Fn()> = Rc::new({
+            let inner_weak = Rc::downgrade(&self.inner);
+            let property = property.clone();
+            move || {
+                let value = property.get();
+
+                // changes done by undo() and redo() are already known
+                let (old_value, last_change) = {
+                    let mut recorder = recorder.borrow_mut();
+                    if recorder.last_value == value {
+                        return;
+                    }
+                    let old_value = std::mem::replace(&mut recorder.last_value, value.clone());
+                    (old_value, recorder.last_change.clone())
+                };
+
+                let inner = if let Some(inner) = inner_weak.upgrade() {
+                    inner
+                } else {
+                    return;
+                };
+
+                if let Some(last_change) = last_change {
+                    if UndoManagerInner::try_merge(&inner, target_id) {
+                        last_change.borrow_mut().1 = value;
+                        return;
+                    }
+                }
+
+                let change = Rc::new(RefCell::new((old_value, value)));
+                recorder.borrow_mut().last_change = Some(change.clone());
+
+                let undo = {
+                    let change = change.clone();
+                    let recorder = recorder.clone();
+                    let property = property.clone();
+                    move || {
+                        let old_value = change.borrow().0.clone();
+                        recorder.borrow_mut().last_value = old_value.clone();
+                        property.clone().set(old_value);
+                    }
+                };
+                let redo = {
+                    let recorder = recorder.clone();
+                    let property = property.clone();
+                    move || {
+                        let new_value = change.borrow().1.clone();
+                        recorder.borrow_mut().last_value = new_value.clone();
+                        property.clone().set(new_value);
+                    }
+                };
+                UndoManagerInner::record(&inner, target_id, false, Box::new(undo), Box::new(redo));
+            }
+        });
+
+        let subscription = property.on_changed({
+            let poll = poll.clone();
+            move |_| poll()
+        });
+
+        let mut inner = self.inner.borrow_mut();
+        inner.subscriptions.push(subscription);
+        inner.property_pollers.push(poll);
+    }
+
+    ///
+    /// Records changes of the observable vector owned by `owner`
+    /// (typically the view model).
+    ///
+    /// The owner must not be borrowed when calling `undo()` and `redo()`.
+    ///
+    pub fn register_vec<O, T, F>(&self, owner: &Rc<RefCell<O>>, get_vec: F)
+    where
+        O: 'static,
+        T: 'static + Clone,
+        F: 'static + Fn(&mut O) -> &mut ObservableVec<T>,
+    {
+        let target_id = self.inner.borrow_mut().new_target_id();
+        let owner_weak = Rc::downgrade(owner);
+        let get_vec = Rc::new(get_vec);
+
+        let mut owner_ref = owner.borrow_mut();
+        let vec = get_vec(&mut owner_ref);
+
+        // copy of the vector used to get removed values
+        let mut items: Vec<T> = vec.into_iter().cloned().collect();
+
+        // number of notifications about changes done by undo() and redo()
+        let own_changes = Rc::new(RefCell::new(0usize));
+
+        let subscription = vec.on_changed({
+            let inner_weak = Rc::downgrade(&self.inner);
+            move |change: VecDiff<T>| {
+                let inverse_change = invert_and_apply(change.clone(), &mut items);
+
+                if *own_changes.borrow() > 0 {
+                    *own_changes.borrow_mut() -= 1;
+                    return;
+                }
+
+                let inner = if let Some(inner) = inner_weak.upgrade() {
+                    inner
+                } else {
+                    return;
+                };
+
+                let apply = |change: VecDiff<T>| {
+                    let owner_weak = owner_weak.clone();
+                    let get_vec = get_vec.clone();
+                    let own_changes = own_changes.clone();
+                    move || {
+                        if let Some(owner) = owner_weak.upgrade() {
+                            *own_changes.borrow_mut() += 1;
+                            get_vec(&mut owner.borrow_mut()).apply_change(change.clone());
+                        }
+                    }
+                };
+                let undo = apply(inverse_change);
+                let redo = apply(change);
+                UndoManagerInner::record(&inner, target_id, true, Box::new(undo), Box::new(redo));
+            }
+        });
+
+        self.inner
+            .borrow_mut()
+            .subscriptions
+            .push(Subscription::EventSubscription(subscription));
+    }
+
+    ///
+    /// Starts grouping of the changes into one step.
+    ///
+    /// Transactions can be nested.
+    ///
+    pub fn begin_transaction(&self) {
+        // changes done before the transaction are not part of it
+        UndoManagerInner::poll_properties(&self.inner);
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.transaction_depth += 1;
+            inner.is_merge_allowed = false;
+        }
+
+        // notifications about collection changes are queued,
+        // so the transaction applies to them after the already queued ones
+        let inner_weak = Rc::downgrade(&self.inner);
+        spawn_local_and_forget(async move {
+            if let Some(inner) = inner_weak.upgrade() {
+                inner.borrow_mut().queued_transaction_depth += 1;
+            }
+        });
+    }
+
+    pub fn end_transaction(&self) {
+        UndoManagerInner::poll_properties(&self.inner);
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.transaction_depth = inner.transaction_depth.saturating_sub(1);
+        }
+
+        let inner_weak = Rc::downgrade(&self.inner);
+        spawn_local_and_forget(async move {
+            if let Some(inner) = inner_weak.upgrade() {
+                {
+                    let mut inner = inner.borrow_mut();
+                    if inner.queued_transaction_depth > 0 {
+                        inner.queued_transaction_depth -= 1;
+                        if inner.queued_transaction_depth == 0 {
+                            if let Some(step) = inner.transaction_step.take() {
+                                inner.push_step(step);
+                                inner.redo_steps.clear();
+                            }
+                            inner.is_merge_allowed = false;
+                        }
+                    }
+                }
+                UndoManagerInner::update_can_undo_redo(&inner);
+            }
+        });
+    }
+
+    ///
+    /// Groups all the changes done by `f` into one step.
+    ///
+    pub fn transaction<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.begin_transaction();
+        let result = f();
+        self.end_transaction();
+        result
+    }
+
+    ///
+    /// Undoes the last step. Returns false if there is nothing to undo.
+    ///
+    pub fn undo(&self) -> bool {
+        let step = {
+            let mut inner = self.inner.borrow_mut();
+            inner.is_merge_allowed = false;
+            inner.undo_steps.pop_back()
+        };
+
+        if let Some(step) = step {
+            // called when the manager is not borrowed
+            for change in step.changes.iter().rev() {
+                (change.undo)();
+            }
+
+            self.inner.borrow_mut().redo_steps.push(step);
+            UndoManagerInner::update_can_undo_redo(&self.inner);
+            true
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Redoes the last undone step. Returns false if there is nothing to redo.
+    ///
+    pub fn redo(&self) -> bool {
+        let step = {
+            let mut inner = self.inner.borrow_mut();
+            inner.is_merge_allowed = false;
+            inner.redo_steps.pop()
+        };
+
+        if let Some(step) = step {
+            // called when the manager is not borrowed
+            for change in step.changes.iter() {
+                (change.redo)();
+            }
+
+            self.inner.borrow_mut().undo_steps.push_back(step);
+            UndoManagerInner::update_can_undo_redo(&self.inner);
+            true
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Removes all the undo and redo steps.
+    ///
+    pub fn clear(&self) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.undo_steps.clear();
+            inner.redo_steps.clear();
+            inner.is_merge_allowed = false;
+        }
+        UndoManagerInner::update_can_undo_redo(&self.inner);
+    }
+}
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        UndoManager::new()
+    }
+}
+
+impl UndoManagerInner {
+    fn new_target_id(&mut self) -> usize {
+        let target_id = self.next_target_id;
+        self.next_target_id += 1;
+        target_id
+    }
+
+    fn record(
+        inner: &Rc<RefCell<UndoManagerInner>>,
+        target_id: usize,
+        is_queued: bool,
+        undo: Box<dyn Fn()>,
+        redo: Box<dyn Fn()>,
+    ) {
+        let change = UndoChange {
+            target_id,
+            time: Instant::now(),
+            undo,
+            redo,
+        };
+
+        {
+            let mut inner = inner.borrow_mut();
+            let transaction_depth = if is_queued {
+                inner.queued_transaction_depth
+            } else {
+                inner.transaction_depth
+            };
+            if transaction_depth > 0 {
+                inner
+                    .transaction_step
+                    .get_or_insert_with(|| UndoStep {
+                        changes: Vec::new(),
+                    })
+                    .changes
+                    .push(change);
+            } else {
+                inner.push_step(UndoStep {
+                    changes: vec![change],
+                });
+                inner.is_merge_allowed = true;
+            }
+            inner.redo_steps.clear();
+        }
+
+        UndoManagerInner::update_can_undo_redo(inner);
+    }
+
+    ///
+    /// Returns true if the change of the target can be merged with the last change
+    /// (the last step is a single change of the same target done recently).
+    ///
+    fn try_merge(inner: &Rc<RefCell<UndoManagerInner>>, target_id: usize) -> bool {
+        let is_merged = {
+            let mut inner = inner.borrow_mut();
+            let merge_interval = match inner.merge_interval {
+                Some(merge_interval)
+                    if inner.is_merge_allowed
+                        && inner.transaction_depth == 0
+                        && inner.queued_transaction_depth == 0 =>
+                {
+                    merge_interval
+                }
+                _ => return false,
+            };
+
+            match inner
+                .undo_steps
+                .back_mut()
+                .map(|step| step.changes.as_mut_slice())
+            {
+                Some([change]) if change.target_id == target_id => {
+                    let now = Instant::now();
+                    if now - change.time <= merge_interval {
+                        change.time = now;
+                        inner.redo_steps.clear();
+                        true
+                    } else {
+                        false
+                    }
+                }
+                _ => false,
+            }
+        };
+
+        if is_merged {
+            UndoManagerInner::update_can_undo_redo(inner);
+        }
+        is_merged
+    }
+
+    fn poll_properties(inner: &Rc<RefCell<UndoManagerInner>>) {
+        // called when the manager is not borrowed
+        let property_pollers = inner.borrow().property_pollers.clone();
+        for poll in property_pollers {
+            poll();
+        }
+    }
+
+    fn push_step(&mut self, step: UndoStep) {
+        self.undo_steps.push_back(step);
+        self.apply_history_limit();
+    }
+
+    fn apply_history_limit(&mut self) {
+        while self.undo_steps.len() > self.history_limit {
+            self.undo_steps.pop_front();
+        }
+    }
+
+    ///
+    /// Properties are set when the manager is not borrowed,
+    /// because setting them can run other handlers (in synchronous mode).
+    ///
+    fn update_can_undo_redo(inner: &Rc<RefCell<UndoManagerInner>>) {
+        let (mut can_undo, mut can_redo, has_undo_steps, has_redo_steps) = {
+            let inner = inner.borrow();
+            (
+                inner.can_undo.clone(),
+                inner.can_redo.clone(),
+                !inner.undo_steps.is_empty(),
+                !inner.redo_steps.is_empty(),
+            )
+        };
+        can_undo.set(has_undo_steps);
+        can_redo.set(has_redo_steps);
+    }
+}
+
+///
+/// Applies the change to the items and returns the change reverting it.
+///
+fn invert_and_apply<T: Clone>(change: VecDiff<T>, items: &mut Vec<T>) -> VecDiff<T> {
+    match change {
+        VecDiff::Clear {} => {
+            let changes = items
+                .drain(..)
+                .enumerate()
+                .map(|(index, value)| VecDiff::InsertAt { index, value })
+                .collect();
+            VecDiff::Batch { changes }
+        }
+        VecDiff::InsertAt { index, value } => {
+            items.insert(index, value);
+            VecDiff::RemoveAt { index }
+        }
+        VecDiff::RemoveAt { index } => {
+            let value = items.remove(index);
+            VecDiff::InsertAt { index, value }
+        }
+        VecDiff::Replace { index, value } => {
+            let value = std::mem::replace(&mut items[index], value);
+            VecDiff::Replace { index, value }
+        }
+        VecDiff::Move {
+            old_index,
+            new_index,
+        } => {
+            let value = items.remove(old_index);
+            items.insert(new_index, value);
+            VecDiff::Move {
+                old_index: new_index,
+                new_index: old_index,
+            }
+        }
+        VecDiff::Batch { changes } => {
+            let mut inverse_changes: Vec<VecDiff<T>> = changes
+                .into_iter()
+                .map(|change| invert_and_apply(change, items))
+                .collect();
+            inverse_changes.reverse();
+            VecDiff::Batch {
+                changes: inverse_changes,
+            }
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use futures_signals::signal::SignalExt;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock, Weak};
+
+use crate::task::synchronous_executor::SynchronousExecutor;
+use crate::{spawn_local, Property, ReadOnlyProperty, Subscription};
+
+type Validator<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+///
+/// Validation state of the property.
+///
+/// Errors are the conversion error (if any) followed by
+/// the errors returned from the validators.
+///
+pub(crate) struct Validation<T> {
+    validators: Vec<Validator<T>>,
+    validator_errors: Vec<String>,
+    conversion_error: Option<String>,
+
+    errors: Property<Vec<String>>,
+
+    // false when errors are shared with other property (of the converter binding)
+    // and only the other property can modify them
+    owns_errors: bool,
+
+    value_changed_subscription: Option<Subscription>,
+}
+
+impl<T: 'static + Clone + PartialEq> Validation<T> {
+    fn new() -> Self {
+        Validation {
+            validators: Vec::new(),
+            validator_errors: Vec::new(),
+            conversion_error: None,
+            errors: Property::new(Vec::new()),
+            owns_errors: true,
+            value_changed_subscription: None,
+        }
+    }
+
+    fn shared(errors: Property<Vec<String>>) -> Self {
+        Validation {
+            owns_errors: false,
+            errors,
+            ..Validation::new()
+        }
+    }
+
+    fn run_validators(&mut self, value: &T) {
+        if !self.owns_errors || self.validators.is_empty() {
+            return;
+        }
+
+        self.validator_errors = self
+            .validators
+            .iter()
+            .filter_map(|validator| validator(value).err())
+            .collect();
+        self.update_errors();
+    }
+
+    fn update_errors(&mut self) {
+        if !self.owns_errors {
+            return;
+        }
+
+        let errors = self
+            .conversion_error
+            .iter()
+            .chain(self.validator_errors.iter())
+            .cloned()
+            .collect();
+
+        // set without flushing the synchronous executor,
+        // the validation is locked here and woken tasks could try to lock it again
+        self.errors.data.set_neq(errors);
+    }
+
+    pub(crate) fn set_conversion_error(
+        validation: &Weak<RwLock<Option<Validation<T>>>>,
+        conversion_error: Option<String>,
+    ) {
+        if let Some(validation) = validation.upgrade() {
+            let mut validation = validation.write().unwrap();
+            if validation.is_none() && conversion_error.is_none() {
+                return;
+            }
+
+            let validation = validation.get_or_insert_with(Validation::new);
+            if validation.conversion_error != conversion_error {
+                validation.conversion_error = conversion_error;
+                validation.update_errors();
+            }
+        }
+    }
+}
+
+impl<T: 'static + Clone + PartialEq> Property<T> {
+    ///
+    /// Adds validation rule.
+    ///
+    /// Validators are run every time the value changes (also by binding).
+    /// Validator returns error message when the value is invalid.
+    ///
+    /// Example:
+    ///
+    /// let mut name = Property::new("");
+    /// name.add_validator(|name: &String| {
+    ///     if name.is_empty() { Err("Name is required".to_string()) } else { Ok(()) }
+    /// });
+    ///
+    pub fn add_validator<F>(&mut self, f: F)
+    where
+        F: 'static + Fn(&T) -> Result<(), String>,
+    {
+        let is_subscribed = {
+            let mut validation = self.validation.write().unwrap();
+            let validation = validation.get_or_insert_with(Validation::new);
+            validation.validators.push(Box::new(f));
+            validation.run_validators(&self.data.get_cloned());
+            validation.value_changed_subscription.is_some()
+        };
+
+        // spawned when the validation is not locked,
+        // because the task can be polled immediately (in synchronous mode)
+        if !is_subscribed {
+            let validation_weak = Arc::downgrade(&self.validation);
+            let subscription = Subscription::SpawnLocal(spawn_local(
+                self.data.signal_cloned().for_each(move |v| {
+                    if let Some(validation) = validation_weak.upgrade() {
+                        if let Some(validation) = validation.write().unwrap().as_mut() {
+                            validation.run_validators(&v);
+                        }
+                    }
+                    async {}
+                }),
+            ));
+            if let Some(validation) = self.validation.write().unwrap().as_mut() {
+                validation.value_changed_subscription = Some(subscription);
+            }
+        }
+
+        SynchronousExecutor::flush_if_enabled();
+    }
+
+    pub fn with_validator<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(&T) -> Result<(), String>,
+    {
+        self.add_validator(f);
+        self
+    }
+
+    ///
+    /// Runs validators for the current value.
+    ///
+    /// Returns true if the property is valid.
+    ///
+    pub fn validate(&self) -> bool {
+        let is_valid = if let Some(validation) = self.validation.write().unwrap().as_mut() {
+            validation.run_validators(&self.data.get_cloned());
+            validation.errors.get().is_empty()
+        } else {
+            true
+        };
+        SynchronousExecutor::flush_if_enabled();
+        is_valid
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if let Some(validation) = self.validation.read().unwrap().as_ref() {
+            validation.errors.get().is_empty()
+        } else {
+            true
+        }
+    }
+
+    ///
+    /// Observable list of validation errors.
+    ///
+    pub fn errors(&self) -> ReadOnlyProperty<Vec<String>> {
+        self.validation
+            .write()
+            .unwrap()
+            .get_or_insert_with(Validation::new)
+            .errors
+            .read_only()
+    }
+
+    ///
+    /// Observable list of validation errors if the property has any validation
+    /// (validators or errors shared with the converter binding).
+    ///
+    /// Unlike `errors()`, it does not create the validation state,
+    /// so it can be used by controls showing errors of any property.
+    ///
+    pub fn existing_errors(&self) -> Option<ReadOnlyProperty<Vec<String>>> {
+        self.validation
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|validation| validation.errors.read_only())
+    }
+
+    ///
+    /// Makes this property to show the same validation errors as the `other` property.
+    ///
+    pub(crate) fn share_errors_with<TOther>(&mut self, other: &Property<TOther>)
+    where
+        TOther: 'static + Clone + PartialEq,
+    {
+        let errors = other
+            .validation
+            .write()
+            .unwrap()
+            .get_or_insert_with(Validation::new)
+            .errors
+            .clone();
+        self.validation
+            .write()
+            .unwrap()
+            .replace(Validation::shared(errors));
+    }
+}
+
+///
+/// Aggregates validation state of many properties.
+///
+/// Typically used to get validation state of the whole view model
+/// (for example to enable or disable the submit button).
+///
+/// Example:
+///
+/// let validation_group = ValidationGroup::new()
+///     .with(&vm.name)
+///     .with(&vm.age);
+///
+/// ui! { Text { text: (&validation_group.is_valid(), |v| if v { "OK" } else { "Invalid" }) }}
+///
+pub struct ValidationGroup {
+    errors: Rc<RefCell<Vec<ReadOnlyProperty<Vec<String>>>>>,
+    validators: Vec<Box<dyn Fn() -> bool>>,
+    is_valid: Property<bool>,
+    _errors_changed_subscriptions: Vec<Subscription>,
+}
+
+impl Default for ValidationGroup {
+    fn default() -> Self {
+        ValidationGroup::new()
+    }
+}
+
+impl ValidationGroup {
+    pub fn new() -> Self {
+        ValidationGroup {
+            errors: Rc::new(RefCell::new(Vec::new())),
+            validators: Vec::new(),
+            is_valid: Property::new(true),
+            _errors_changed_subscriptions: Vec::new(),
+        }
+    }
+
+    pub fn add<T>(&mut self, property: &Property<T>)
+    where
+        T: 'static + Clone + PartialEq,
+    {
+        let errors = property.errors();
+
+        let subscription = errors.on_changed({
+            let all_errors = self.errors.clone();
+            let mut is_valid = self.is_valid.clone();
+            move |_| is_valid.set(ValidationGroup::calc_is_valid(&all_errors.borrow()))
+        });
+        self._errors_changed_subscriptions.push(subscription);
+
+        self.errors.borrow_mut().push(errors);
+        self.is_valid
+            .set(ValidationGroup::calc_is_valid(&self.errors.borrow()));
+
+        let property = property.clone();
+        self.validators.push(Box::new(move || property.validate()));
+    }
+
+    pub fn with<T>(mut self, property: &Property<T>) -> Self
+    where
+        T: 'static + Clone + PartialEq,
+    {
+        self.add(property);
+        self
+    }
+
+    ///
+    /// Runs validators of all the properties.
+    ///
+    /// Returns true if all the properties are valid.
+    ///
+    pub fn validate(&mut self) -> bool {
+        // every property is validated (not only until the first invalid one),
+        // so all the errors are shown
+        let mut is_valid = true;
+        for validate in &self.validators {
+            is_valid &= validate();
+        }
+        self.is_valid.set(is_valid);
+        is_valid
+    }
+
+    pub fn is_valid(&self) -> ReadOnlyProperty<bool> {
+        self.is_valid.read_only()
+    }
+
+    ///
+    /// All the validation errors of all the properties.
+    ///
+    pub fn errors(&self) -> Vec<String> {
+        self.errors
+            .borrow()
+            .iter()
+            .flat_map(|errors| errors.get())
+            .collect()
+    }
+
+    fn calc_is_valid(errors: &[ReadOnlyProperty<Vec<String>>]) -> bool {
+        errors.iter().all(|errors| errors.get().is_empty())
+    }
+}
@@ -0,0 +1,234 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+use tokio::sync::oneshot;
+
+use crate::{spawn_local_and_forget, Event, EventSubscription};
+
+thread_local! {
+    static CURRENT_MESSAGE_BUS: RefCell<Option<MessageBus>> = const { RefCell::new(None) };
+}
+
+///
+/// Message sent with `MessageBus::request()` and answered by a single handler.
+///
+/// Example:
+///
+/// #[derive(Clone)]
+/// struct GetCurrentTheme;
+///
+/// impl Request for GetCurrentTheme {
+///     type Response = Theme;
+/// }
+///
+pub trait Request: 'static + Clone {
+    type Response: 'static;
+}
+
+///
+/// Request together with the channel for the response.
+///
+/// The response is sent by the first handler, the request is answered with `None`
+/// when there is no handler (the last clone of the envelope is dropped).
+///
+struct RequestEnvelope<R: Request> {
+    request: R,
+    response_sender: Rc<RefCell<Option<oneshot::Sender<R::Response>>>>,
+}
+
+impl<R: Request> Clone for RequestEnvelope<R> {
+    fn clone(&self) -> Self {
+        RequestEnvelope {
+            request: self.request.clone(),
+            response_sender: self.response_sender.clone(),
+        }
+    }
+}
+
+impl<R: Request> RequestEnvelope<R> {
+    fn take_response_sender(&self) -> Option<oneshot::Sender<R::Response>> {
+        self.response_sender.borrow_mut().take()
+    }
+}
+
+///
+/// Typed publish / subscribe channel for loosely coupled view models.
+///
+/// Messages are delivered to the subscribers of the message type
+/// (later, on the same thread, like the `Callback`s). The bus keeps
+/// only weak references to the handlers, so the handler is removed
+/// when the returned `EventSubscription` is dropped.
+///
+/// View models of all the windows (running on the same VM thread)
+/// can use the common bus returned by `MessageBus::current()`.
+///
+/// Example:
+///
+/// #[derive(Clone)]
+/// struct ThemeChanged(Theme);
+///
+/// // settings window
+/// MessageBus::current().publish(ThemeChanged(theme));
+///
+/// // every window
+/// vm.theme_subscription = MessageBus::current().subscribe_vm(&vm_rc,
+///     |vm: &mut MainViewModel, ThemeChanged(theme)| vm.theme.set(theme));
+///
+#[derive(Clone)]
+pub struct MessageBus {
+    // Rc<RefCell<Event<M>>> by type of the message
+    events: Rc<RefCell<HashMap<TypeId, Box<dyn Any>>>>,
+}
+
+impl MessageBus {
+    pub fn new() -> Self {
+        MessageBus {
+            events: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    ///
+    /// Returns the common bus of the current thread.
+    ///
+    pub fn current() -> MessageBus {
+        CURRENT_MESSAGE_BUS.with(|current| {
+            current
+                .borrow_mut()
+                .get_or_insert_with(MessageBus::new)
+                .clone()
+        })
+    }
+
+    ///
+    /// Sends the message to all the subscribers of its type.
+    ///
+    pub fn publish<M: 'static + Clone>(&self, message: M) {
+        // event is not created if nobody has subscribed
+        let event = self
+            .events
+            .borrow()
+            .get(&TypeId::of::<M>())
+            .and_then(|event| event.downcast_ref::<Rc<RefCell<Event<M>>>>())
+            .cloned();
+        if let Some(event) = event {
+            event.borrow().emit(message);
+        }
+    }
+
+    #[track_caller]
+    pub fn subscribe<M, F>(&self, f: F) -> EventSubscription
+    where
+        M: 'static + Clone,
+        F: 'static + FnMut(M),
+    {
+        self.get_event::<M>().borrow_mut().subscribe(f)
+    }
+
+    ///
+    /// Subscribes handler returning future (spawned for every message).
+    ///
+    #[track_caller]
+    pub fn subscribe_async<M, F, Fut>(&self, mut f: F) -> EventSubscription
+    where
+        M: 'static + Clone,
+        F: 'static + FnMut(M) -> Fut,
+        Fut: 'static + Future<Output = ()>,
+    {
+        self.subscribe(move |message| spawn_local_and_forget(f(message)))
+    }
+
+    ///
+    /// Subscribes handler of the view model.
+    ///
+    /// The bus keeps only weak reference to the view model,
+    /// so the subscription can be stored in the view model itself.
+    ///
+    #[track_caller]
+    pub fn subscribe_vm<V, M, F>(&self, vm: &Rc<RefCell<V>>, mut f: F) -> EventSubscription
+    where
+        V: 'static,
+        M: 'static + Clone,
+        F: 'static + FnMut(&mut V, M),
+    {
+        let vm_weak = Rc::downgrade(vm);
+        self.subscribe(move |message| {
+            if let Some(vm) = vm_weak.upgrade() {
+                f(&mut vm.borrow_mut(), message);
+            }
+        })
+    }
+
+    ///
+    /// Sends the request and waits for the response.
+    ///
+    /// Returns `None` when no handler has answered the request.
+    ///
+    /// Example:
+    ///
+    /// let theme = MessageBus::current().request(GetCurrentTheme).await;
+    ///
+    pub async fn request<R: Request>(&self, request: R) -> Option<R::Response> {
+        let (sender, receiver) = oneshot::channel();
+        self.publish(RequestEnvelope {
+            request,
+            response_sender: Rc::new(RefCell::new(Some(sender))),
+        });
+        receiver.await.ok()
+    }
+
+    ///
+    /// Registers handler answering the requests.
+    ///
+    /// When there are more handlers, the first one answers the request.
+    ///
+    #[track_caller]
+    pub fn on_request<R, F>(&self, mut f: F) -> EventSubscription
+    where
+        R: Request,
+        F: 'static + FnMut(R) -> R::Response,
+    {
+        self.subscribe(move |envelope: RequestEnvelope<R>| {
+            if let Some(sender) = envelope.take_response_sender() {
+                let _ = sender.send(f(envelope.request));
+            }
+        })
+    }
+
+    ///
+    /// Registers handler answering the requests asynchronously.
+    ///
+    #[track_caller]
+    pub fn on_request_async<R, F, Fut>(&self, mut f: F) -> EventSubscription
+    where
+        R: Request,
+        F: 'static + FnMut(R) -> Fut,
+        Fut: 'static + Future<Output = R::Response>,
+    {
+        self.subscribe(move |envelope: RequestEnvelope<R>| {
+            if let Some(sender) = envelope.take_response_sender() {
+                let response = f(envelope.request);
+                spawn_local_and_forget(async move {
+                    let _ = sender.send(response.await);
+                });
+            }
+        })
+    }
+
+    fn get_event<M: 'static + Clone>(&self) -> Rc<RefCell<Event<M>>> {
+        self.events
+            .borrow_mut()
+            .entry(TypeId::of::<M>())
+            .or_insert_with(|| Box::new(Rc::new(RefCell::new(Event::<M>::new()))))
+            .downcast_ref::<Rc<RefCell<Event<M>>>>()
+            .unwrap()
+            .clone()
+    }
+}
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        MessageBus::new()
+    }
+}
@@ -3,3 +3,6 @@ pub use self::services::*;
 
 mod window_service;
 pub use self::window_service::*;
+
+mod message_bus;
+pub use self::message_bus::*;
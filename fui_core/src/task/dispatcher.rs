@@ -0,0 +1,72 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use crate::spawn_local_and_forget;
+
+pub type DispatchedFn = Box<dyn 'static + Send + FnOnce()>;
+
+thread_local! {
+    static CURRENT_DISPATCHER: RefCell<Option<Dispatcher>> = const { RefCell::new(None) };
+}
+
+///
+/// Posts closures from any thread to be executed on the thread owning the dispatcher
+/// (the VM thread).
+///
+/// The application registers its message loop as the dispatcher of the VM thread.
+/// When no dispatcher is registered, the default one executes the closures
+/// with `spawn_local()` on the thread where it was created.
+///
+#[derive(Clone)]
+pub struct Dispatcher {
+    post_func: Arc<dyn Fn(DispatchedFn) + Send + Sync>,
+}
+
+impl Dispatcher {
+    pub fn new<F>(post_func: F) -> Self
+    where
+        F: 'static + Fn(DispatchedFn) + Send + Sync,
+    {
+        Dispatcher {
+            post_func: Arc::new(post_func),
+        }
+    }
+
+    ///
+    /// Registers the dispatcher of the current thread.
+    ///
+    pub fn set_current(dispatcher: Option<Dispatcher>) {
+        CURRENT_DISPATCHER.with(|current| *current.borrow_mut() = dispatcher);
+    }
+
+    ///
+    /// Returns the dispatcher of the current thread.
+    ///
+    /// The default dispatcher is created if none is registered.
+    ///
+    pub fn current() -> Dispatcher {
+        CURRENT_DISPATCHER.with(|current| {
+            current
+                .borrow_mut()
+                .get_or_insert_with(Dispatcher::new_local)
+                .clone()
+        })
+    }
+
+    pub fn post<F: 'static + Send + FnOnce()>(&self, f: F) {
+        (self.post_func)(Box::new(f));
+    }
+
+    fn new_local() -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<DispatchedFn>();
+        spawn_local_and_forget(async move {
+            while let Some(f) = receiver.recv().await {
+                f();
+            }
+        });
+        Dispatcher::new(move |f| {
+            let _ = sender.send(f);
+        })
+    }
+}
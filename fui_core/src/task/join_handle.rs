@@ -1,18 +1,45 @@
+use crate::task::synchronous_executor::SynchronousExecutor;
+use crate::{DiagnosticsToken, ResourceKind};
+use std::marker::PhantomData;
+
 ///
 /// JoinHandle will abort the task when dropped.
 ///
 pub struct JoinHandle<T> {
-    handle: tokio::task::JoinHandle<T>,
+    handle: JoinHandleKind<T>,
+    _diagnostics: DiagnosticsToken,
+}
+
+enum JoinHandleKind<T> {
+    Tokio(tokio::task::JoinHandle<T>),
+
+    // task spawned in synchronous mode
+    Synchronous(usize, PhantomData<T>),
 }
 
 impl<T> JoinHandle<T> {
+    #[track_caller]
     pub fn new(handle: tokio::task::JoinHandle<T>) -> Self {
-        JoinHandle { handle }
+        JoinHandle {
+            handle: JoinHandleKind::Tokio(handle),
+            _diagnostics: DiagnosticsToken::new(ResourceKind::Task),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn new_synchronous(task_id: usize) -> Self {
+        JoinHandle {
+            handle: JoinHandleKind::Synchronous(task_id, PhantomData),
+            _diagnostics: DiagnosticsToken::new(ResourceKind::Task),
+        }
     }
 }
 
 impl<T> Drop for JoinHandle<T> {
     fn drop(&mut self) {
-        self.handle.abort();
+        match &self.handle {
+            JoinHandleKind::Tokio(handle) => handle.abort(),
+            JoinHandleKind::Synchronous(task_id, _) => SynchronousExecutor::abort(*task_id),
+        }
     }
 }
@@ -3,3 +3,8 @@ pub use spawn::*;
 
 mod join_handle;
 pub use join_handle::*;
+
+mod dispatcher;
+pub use dispatcher::*;
+
+pub(crate) mod synchronous_executor;
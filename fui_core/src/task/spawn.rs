@@ -1,15 +1,23 @@
+use crate::task::synchronous_executor::SynchronousExecutor;
 use crate::JoinHandle;
 use std::future::Future;
 
 ///
 /// Spawn task on the same thread and return handle that will abort it on drop.
 ///
+#[track_caller]
 pub fn spawn_local<F>(future: F) -> JoinHandle<F::Output>
 where
     F: Future + 'static,
     F::Output: 'static,
 {
-    JoinHandle::new(tokio::task::spawn_local(future))
+    if SynchronousExecutor::is_enabled() {
+        JoinHandle::new_synchronous(SynchronousExecutor::spawn(async move {
+            future.await;
+        }))
+    } else {
+        JoinHandle::new(tokio::task::spawn_local(future))
+    }
 }
 
 ///
@@ -19,5 +27,11 @@ pub fn spawn_local_and_forget<F>(future: F)
 where
     F: Future + 'static,
 {
-    tokio::task::spawn_local(future);
+    if SynchronousExecutor::is_enabled() {
+        SynchronousExecutor::spawn(async move {
+            future.await;
+        });
+    } else {
+        tokio::task::spawn_local(future);
+    }
 }
@@ -0,0 +1,218 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Wake, Waker};
+
+///
+/// Simple single-threaded executor used when synchronous mode is enabled.
+///
+/// Spawned tasks are polled immediately and every task woken
+/// (for example by changing a property value) is polled again
+/// by the `flush()` until there is nothing left to do.
+///
+/// It makes binding propagation deterministic and allows to use properties
+/// without tokio's LocalSet (useful for unit tests of view models).
+///
+pub(crate) struct SynchronousExecutor {
+    is_enabled: bool,
+    is_flushing: bool,
+    next_task_id: usize,
+    tasks: HashMap<usize, Task>,
+
+    // tasks aborted while they are being polled
+    aborted_task_ids: HashSet<usize>,
+}
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    waker: Arc<TaskWaker>,
+}
+
+struct TaskWaker {
+    is_woken: AtomicBool,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.is_woken.store(true, Ordering::SeqCst);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.is_woken.store(true, Ordering::SeqCst);
+    }
+}
+
+thread_local! {
+    static EXECUTOR: RefCell<SynchronousExecutor> = RefCell::new(SynchronousExecutor {
+        is_enabled: false,
+        is_flushing: false,
+        next_task_id: 0,
+        tasks: HashMap::new(),
+        aborted_task_ids: HashSet::new(),
+    });
+}
+
+impl SynchronousExecutor {
+    pub fn is_enabled() -> bool {
+        EXECUTOR.with(|executor| executor.borrow().is_enabled)
+    }
+
+    pub fn set_enabled(is_enabled: bool) {
+        let tasks = EXECUTOR.with(|executor| {
+            let mut executor = executor.borrow_mut();
+            executor.is_enabled = is_enabled;
+            if is_enabled {
+                HashMap::new()
+            } else {
+                std::mem::take(&mut executor.tasks)
+            }
+        });
+
+        // tasks are dropped when the executor is not borrowed,
+        // because dropping them can abort other tasks
+        drop(tasks);
+    }
+
+    ///
+    /// Adds the task and runs it until it is blocked.
+    ///
+    /// Returns task id.
+    ///
+    pub fn spawn<F>(future: F) -> usize
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let task_id = EXECUTOR.with(|executor| {
+            let mut executor = executor.borrow_mut();
+            let task_id = executor.next_task_id;
+            executor.next_task_id += 1;
+            executor.tasks.insert(
+                task_id,
+                Task {
+                    future: Box::pin(future),
+                    waker: Arc::new(TaskWaker {
+                        is_woken: AtomicBool::new(true),
+                    }),
+                },
+            );
+            task_id
+        });
+
+        SynchronousExecutor::flush();
+
+        task_id
+    }
+
+    pub fn abort(task_id: usize) {
+        // try_with(), because it can be called during thread local destruction
+        let task = EXECUTOR
+            .try_with(|executor| {
+                let mut executor = executor.borrow_mut();
+                let task = executor.tasks.remove(&task_id);
+                if task.is_none() && executor.is_flushing {
+                    executor.aborted_task_ids.insert(task_id);
+                }
+                task
+            })
+            .ok()
+            .flatten();
+
+        // dropped when the executor is not borrowed
+        drop(task);
+    }
+
+    pub fn flush_if_enabled() {
+        if SynchronousExecutor::is_enabled() {
+            SynchronousExecutor::flush();
+        }
+    }
+
+    ///
+    /// Polls woken tasks until there are no more woken tasks.
+    ///
+    /// Nested calls (from inside of the tasks) do nothing,
+    /// because the outer call continues the work.
+    ///
+    pub fn flush() {
+        let is_nested = EXECUTOR.with(|executor| {
+            let mut executor = executor.borrow_mut();
+            let is_nested = executor.is_flushing;
+            executor.is_flushing = true;
+            is_nested
+        });
+        if is_nested {
+            return;
+        }
+
+        // the flushing ends also when a task panics
+        let _flushing_guard = FlushingGuard;
+
+        loop {
+            // take out woken tasks, so they can spawn or abort other tasks when polled
+            let mut woken_tasks: Vec<(usize, Task)> = EXECUTOR.with(|executor| {
+                let mut executor = executor.borrow_mut();
+                let woken_task_ids: Vec<usize> = executor
+                    .tasks
+                    .iter()
+                    .filter(|(_, task)| task.waker.is_woken.load(Ordering::SeqCst))
+                    .map(|(task_id, _)| *task_id)
+                    .collect();
+                let mut woken_tasks: Vec<(usize, Task)> = woken_task_ids
+                    .into_iter()
+                    .map(|task_id| (task_id, executor.tasks.remove(&task_id).unwrap()))
+                    .collect();
+                woken_tasks.sort_by_key(|(task_id, _)| *task_id);
+                woken_tasks
+            });
+
+            if woken_tasks.is_empty() {
+                break;
+            }
+
+            for (task_id, mut task) in woken_tasks.drain(..) {
+                let is_aborted = EXECUTOR
+                    .with(|executor| executor.borrow_mut().aborted_task_ids.remove(&task_id));
+                if is_aborted {
+                    continue;
+                }
+
+                task.waker.is_woken.store(false, Ordering::SeqCst);
+                let waker = Waker::from(task.waker.clone());
+                let mut context = Context::from_waker(&waker);
+                if task.future.as_mut().poll(&mut context).is_pending() {
+                    let aborted_task = EXECUTOR.with(|executor| {
+                        let mut executor = executor.borrow_mut();
+                        if !executor.aborted_task_ids.remove(&task_id) && executor.is_enabled {
+                            executor.tasks.insert(task_id, task);
+                            None
+                        } else {
+                            Some(task)
+                        }
+                    });
+
+                    // dropped when the executor is not borrowed
+                    drop(aborted_task);
+                }
+            }
+        }
+    }
+}
+
+///
+/// Ends the flushing when dropped.
+///
+struct FlushingGuard;
+
+impl Drop for FlushingGuard {
+    fn drop(&mut self) {
+        // try_with(), because it can be dropped during thread local destruction
+        let _ = EXECUTOR.try_with(|executor| {
+            let mut executor = executor.borrow_mut();
+            executor.is_flushing = false;
+            executor.aborted_task_ids.clear();
+        });
+    }
+}
@@ -0,0 +1,53 @@
+//!
+//! Helpers for unit testing of view models.
+//!
+//! By default property bindings, `on_changed` handlers and callbacks are
+//! executed asynchronously with tokio's `spawn_local()`, so they require
+//! running LocalSet and an await point to propagate changes.
+//!
+//! In synchronous mode they are executed by the simple executor
+//! on the current thread. Changes are propagated before `Property::set()`
+//! returns and no tokio runtime is needed.
+//!
+//! Example:
+//!
+//! ```
+//! use fui_core::{Property, ReadOnlyProperty};
+//!
+//! fui_core::testing::set_synchronous_mode(true);
+//!
+//! let mut first_name = Property::new("John");
+//! let last_name = Property::new("Smith");
+//! let full_name: ReadOnlyProperty<String> =
+//!     Property::computed2(&first_name, &last_name, |first: String, last: String| {
+//!         format!("{} {}", first, last)
+//!     });
+//!
+//! first_name.set("Ann".to_string());
+//! assert_eq!(full_name.get(), "Ann Smith");
+//! ```
+//!
+
+use crate::task::synchronous_executor::SynchronousExecutor;
+
+///
+/// Enables or disables synchronous mode for the current thread.
+///
+/// Disabling synchronous mode drops all the tasks spawned in synchronous mode.
+///
+pub fn set_synchronous_mode(is_enabled: bool) {
+    SynchronousExecutor::set_enabled(is_enabled);
+}
+
+pub fn is_synchronous_mode() -> bool {
+    SynchronousExecutor::is_enabled()
+}
+
+///
+/// Runs pending binding tasks until nothing is left.
+///
+/// Works only in synchronous mode.
+///
+pub fn flush() {
+    SynchronousExecutor::flush_if_enabled();
+}
@@ -0,0 +1,61 @@
+use fui_core::*;
+
+#[test]
+fn computed_follows_sources() {
+    testing::set_synchronous_mode(true);
+
+    let mut first_name = Property::new("John");
+    let mut last_name = Property::new("Smith");
+    let mut age = Property::new(30);
+
+    let full_name: ReadOnlyProperty<String> =
+        Property::computed2(&first_name, &last_name, |first: String, last: String| {
+            format!("{} {}", first, last)
+        });
+    let label: ReadOnlyProperty<String> = Property::computed3(
+        &first_name,
+        &last_name,
+        &age,
+        |first: String, last: String, age: i32| format!("{} {} ({})", first, last, age),
+    );
+    let total: ReadOnlyProperty<i32> =
+        Property::computed_n(&[&age, &age], |values| values.iter().sum());
+
+    assert_eq!(full_name.get(), "John Smith");
+    assert_eq!(label.get(), "John Smith (30)");
+    assert_eq!(total.get(), 60);
+
+    first_name.set("Ann".to_string());
+    last_name.set("Brown".to_string());
+    age.set(40);
+
+    assert_eq!(full_name.get(), "Ann Brown");
+    assert_eq!(label.get(), "Ann Brown (40)");
+    assert_eq!(total.get(), 80);
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn computed_subscriptions_are_dropped_with_result() {
+    testing::set_synchronous_mode(true);
+    Diagnostics::set_enabled(true);
+
+    let mut source = Property::new(1);
+    let checkpoint = Diagnostics::checkpoint();
+
+    let doubled = Property::computed_n(&[&source], |values| values[0] * 2);
+    let doubled_clone = doubled.clone();
+    assert!(!Diagnostics::live_since(checkpoint).is_empty());
+
+    // the computation lives as long as any clone of the result
+    drop(doubled);
+    source.set(2);
+    assert_eq!(doubled_clone.get(), 4);
+
+    drop(doubled_clone);
+    assert!(Diagnostics::live_since(checkpoint).is_empty());
+
+    Diagnostics::set_enabled(false);
+    testing::set_synchronous_mode(false);
+}
@@ -0,0 +1,92 @@
+use fui_core::*;
+use fui_macros::ui;
+use std::cell::RefCell;
+use std::rc::Rc;
+use typemap::TypeMap;
+
+struct MainViewModel {
+    widths: Property<Vec<(i32, Length)>>,
+    row: Property<i32>,
+}
+
+impl MainViewModel {
+    fn new() -> Self {
+        MainViewModel {
+            widths: Property::new(vec![(0, Length::Auto)]),
+            row: Property::new(1),
+        }
+    }
+}
+
+///
+/// Runs the test with diagnostics enabled and returns the reported leaks.
+///
+fn run<F: FnOnce()>(f: F) -> Vec<LeakReport> {
+    testing::set_synchronous_mode(true);
+    Diagnostics::set_enabled(true);
+    let leaks = Rc::new(RefCell::new(Vec::new()));
+    Diagnostics::set_leak_hook(Some({
+        let leaks = leaks.clone();
+        move |report: &LeakReport| leaks.borrow_mut().push(report.clone())
+    }));
+
+    f();
+
+    Diagnostics::set_leak_hook(None::<fn(&LeakReport)>);
+    Diagnostics::set_enabled(false);
+    testing::set_synchronous_mode(false);
+
+    let leaks = leaks.borrow().clone();
+    leaks
+}
+
+#[test]
+fn closing_window_leaks_nothing() {
+    let leaks = run(|| {
+        let vm = MainViewModel::new();
+        let checkpoint = Diagnostics::checkpoint();
+
+        let window_content = ui!(
+            Grid {
+                columns: 2,
+                widths: &vm.widths,
+
+                Vertical {
+                    Row: &vm.row,
+                },
+            }
+        );
+
+        // bindings of the data fields are owned by the controls
+        let live = Diagnostics::live_since(checkpoint);
+        assert!(!live.is_empty());
+        assert!(live.iter().all(|resource| resource.owner.is_some()));
+
+        drop(window_content);
+        assert!(Diagnostics::live_since(checkpoint).is_empty());
+    });
+    assert!(leaks.is_empty());
+}
+
+#[test]
+fn subscription_outliving_control_is_reported() {
+    let leaks = run(|| {
+        let vm = MainViewModel::new();
+        let leaked_subscription;
+
+        let window_content = ui!(Grid {
+            columns: {
+                leaked_subscription = vm.row.on_changed(|_| ());
+                2
+            },
+        });
+        drop(window_content);
+
+        drop(leaked_subscription);
+    });
+
+    assert_eq!(leaks.len(), 1);
+    assert!(leaks[0].owner.ends_with("Grid"));
+    assert_eq!(leaks[0].resources.len(), 1);
+    assert_eq!(leaks[0].resources[0].kind, ResourceKind::Task);
+}
@@ -0,0 +1,145 @@
+use fui_core::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn record_changes(
+    map: &ObservableMap<String, i32>,
+) -> (Rc<RefCell<Vec<MapDiff<String, i32>>>>, EventSubscription) {
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let subscription = map.on_changed({
+        let changes = changes.clone();
+        move |change| changes.borrow_mut().push(change)
+    });
+    (changes, subscription)
+}
+
+///
+/// Copy of the collection kept up to date by replaying its changes.
+///
+fn replica(
+    source: &dyn ObservableCollection<i32>,
+) -> (Rc<RefCell<Vec<i32>>>, Option<Subscription>) {
+    let items = Rc::new(RefCell::new(source.into_iter().collect::<Vec<i32>>()));
+    let subscription = source.on_changed(Box::new({
+        let items = items.clone();
+        move |change| change.apply_to(&mut items.borrow_mut())
+    }));
+    (items, subscription)
+}
+
+fn content(source: &dyn ObservableCollection<i32>) -> Vec<i32> {
+    source.into_iter().collect()
+}
+
+fn keys(map: &ObservableMap<String, i32>) -> Vec<&str> {
+    map.keys().map(|key| key.as_str()).collect()
+}
+
+#[test]
+fn changes_are_emitted() {
+    testing::set_synchronous_mode(true);
+
+    let mut map = ObservableMap::new();
+    let (changes, _subscription) = record_changes(&map);
+
+    assert_eq!(map.insert("a".to_string(), 1), None);
+    assert_eq!(map.insert("a".to_string(), 2), Some(1));
+    assert!(map.update("a", |value| *value += 1));
+    assert!(!map.update("b", |value| *value += 1));
+    assert_eq!(map.remove("b"), None);
+    assert_eq!(map.remove("a"), Some(3));
+    map.clear();
+
+    assert_eq!(
+        *changes.borrow(),
+        vec![
+            MapDiff::Insert {
+                key: "a".to_string(),
+                value: 1
+            },
+            MapDiff::Update {
+                key: "a".to_string(),
+                value: 2
+            },
+            MapDiff::Update {
+                key: "a".to_string(),
+                value: 3
+            },
+            MapDiff::Remove {
+                key: "a".to_string()
+            },
+            MapDiff::Clear {},
+        ]
+    );
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn insertion_order_is_kept() {
+    let mut map: ObservableMap<String, i32> = (0..10).map(|i| (i.to_string(), i)).collect();
+
+    // most of the keys are removed, so the removed slots are compacted
+    for i in (0..10).filter(|i| i % 4 != 1) {
+        map.remove(&i.to_string());
+    }
+    assert_eq!(keys(&map), vec!["1", "5", "9"]);
+
+    map.insert("0".to_string(), 0);
+    map.insert("5".to_string(), 50);
+    assert!(map.update("9", |value| *value = 90));
+    assert_eq!(keys(&map), vec!["1", "5", "9", "0"]);
+    assert_eq!(
+        map.iter().map(|(_, value)| *value).collect::<Vec<_>>(),
+        vec![1, 50, 90, 0]
+    );
+    assert_eq!(map.get("9"), Some(90));
+    assert_eq!(map.len(), 4);
+
+    map.remove("1");
+    map.remove("9");
+    map.insert("1".to_string(), 10);
+    assert_eq!(keys(&map), vec!["5", "0", "1"]);
+}
+
+#[test]
+fn values_follow_map_changes() {
+    testing::set_synchronous_mode(true);
+
+    let mut map: ObservableMap<String, i32> = vec![("b".to_string(), 5), ("a".to_string(), 7)]
+        .into_iter()
+        .collect();
+    let values = map.values();
+    let sorted_values = map.values_sorted_by(|_, value| *value);
+    let (values_replica, _values_subscription) = replica(&values);
+    let (sorted_replica, _sorted_subscription) = replica(&sorted_values);
+    let check = |expected: Vec<i32>, expected_sorted: Vec<i32>| {
+        assert_eq!(content(&values), expected);
+        assert_eq!(*values_replica.borrow(), expected);
+        assert_eq!(content(&sorted_values), expected_sorted);
+        assert_eq!(*sorted_replica.borrow(), expected_sorted);
+    };
+
+    check(vec![5, 7], vec![5, 7]);
+
+    map.insert("c".to_string(), 1);
+    check(vec![5, 7, 1], vec![1, 5, 7]);
+
+    // values with equal sort keys are kept in insertion order
+    map.insert("d".to_string(), 5);
+    check(vec![5, 7, 1, 5], vec![1, 5, 5, 7]);
+
+    // changed sort key moves the value
+    map.insert("c".to_string(), 9);
+    check(vec![5, 7, 9, 5], vec![5, 5, 7, 9]);
+    map.update("b", |value| *value = 8);
+    check(vec![8, 7, 9, 5], vec![5, 7, 8, 9]);
+
+    map.remove("a");
+    check(vec![8, 9, 5], vec![5, 8, 9]);
+
+    map.clear();
+    check(vec![], vec![]);
+
+    testing::set_synchronous_mode(false);
+}
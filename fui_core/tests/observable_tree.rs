@@ -0,0 +1,118 @@
+use fui_core::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn rows_content(rows: &ObservableTreeRows<&'static str>) -> Vec<(&'static str, usize, bool)> {
+    (0..rows.len())
+        .filter_map(|index| rows.get(index))
+        .map(|row| (row.value, row.depth, row.has_children))
+        .collect()
+}
+
+#[test]
+fn detached_node_id_differs_from_inserted_ids() {
+    let mut tree = ObservableTree::new();
+    let path = tree.push(&[], TreeNode::new("first"));
+
+    let detached = TreeNode::new("detached");
+    assert_ne!(tree.get(&path).unwrap().id(), detached.id());
+    assert_eq!(tree.path_of(detached.id()), None);
+    assert_eq!(tree.path_of(tree.get(&path).unwrap().id()), Some(path));
+}
+
+#[test]
+fn visible_rows_follow_tree_changes() {
+    testing::set_synchronous_mode(true);
+
+    let mut tree = ObservableTree::new();
+    tree.push(
+        &[],
+        TreeNode::new("src").with_children(vec![
+            TreeNode::new("main.rs"),
+            TreeNode::new("lib").with_children(vec![TreeNode::new("mod.rs")]),
+        ]),
+    );
+    tree.push(&[], TreeNode::new("Cargo.toml"));
+
+    let rows = tree.visible_rows();
+    let replica = Rc::new(RefCell::new(
+        (0..rows.len())
+            .filter_map(|index| rows.get(index))
+            .collect::<Vec<_>>(),
+    ));
+    let _subscription = rows.on_changed(Box::new({
+        let replica = replica.clone();
+        move |change| change.apply_to(&mut replica.borrow_mut())
+    }));
+    let check = |expected: Vec<(&'static str, usize, bool)>| {
+        assert_eq!(rows_content(&rows), expected);
+        assert_eq!(
+            replica
+                .borrow()
+                .iter()
+                .map(|row| (row.value, row.depth, row.has_children))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    };
+
+    check(vec![("src", 0, true), ("Cargo.toml", 0, false)]);
+
+    tree.set_expanded(&[0], true);
+    check(vec![
+        ("src", 0, true),
+        ("main.rs", 1, false),
+        ("lib", 1, true),
+        ("Cargo.toml", 0, false),
+    ]);
+
+    tree.set_expanded(&[0, 1], true);
+    check(vec![
+        ("src", 0, true),
+        ("main.rs", 1, false),
+        ("lib", 1, true),
+        ("mod.rs", 2, false),
+        ("Cargo.toml", 0, false),
+    ]);
+
+    // inserting into a collapsed node changes only the parent row
+    tree.push(&[1], TreeNode::new("hidden"));
+    check(vec![
+        ("src", 0, true),
+        ("main.rs", 1, false),
+        ("lib", 1, true),
+        ("mod.rs", 2, false),
+        ("Cargo.toml", 0, true),
+    ]);
+
+    tree.insert(&[0, 1], TreeNode::new("build.rs"));
+    tree.set(&[0, 0], "app.rs");
+    check(vec![
+        ("src", 0, true),
+        ("app.rs", 1, false),
+        ("build.rs", 1, false),
+        ("lib", 1, true),
+        ("mod.rs", 2, false),
+        ("Cargo.toml", 0, true),
+    ]);
+
+    tree.remove(&[0, 2, 0]);
+    check(vec![
+        ("src", 0, true),
+        ("app.rs", 1, false),
+        ("build.rs", 1, false),
+        ("lib", 1, false),
+        ("Cargo.toml", 0, true),
+    ]);
+
+    tree.set_expanded(&[0], false);
+    check(vec![("src", 0, true), ("Cargo.toml", 0, true)]);
+
+    tree.remove(&[0]);
+    check(vec![("Cargo.toml", 0, true)]);
+
+    tree.clear();
+    check(vec![]);
+
+    testing::set_synchronous_mode(false);
+}
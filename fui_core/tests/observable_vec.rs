@@ -0,0 +1,171 @@
+use fui_core::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+///
+/// Copy of the collection kept up to date by replaying its changes.
+///
+struct Replica<T: 'static + Clone> {
+    items: Rc<RefCell<Vec<T>>>,
+    changes_count: Rc<RefCell<usize>>,
+    _subscription: Option<Subscription>,
+}
+
+impl<T: 'static + Clone> Replica<T> {
+    fn new(source: &dyn ObservableCollection<T>) -> Self {
+        let items = Rc::new(RefCell::new(source.into_iter().collect::<Vec<T>>()));
+        let changes_count = Rc::new(RefCell::new(0));
+        let subscription = source.on_changed(Box::new({
+            let items = items.clone();
+            let changes_count = changes_count.clone();
+            move |change| {
+                *changes_count.borrow_mut() += 1;
+                change.apply_to(&mut items.borrow_mut());
+            }
+        }));
+        Replica {
+            items,
+            changes_count,
+            _subscription: subscription,
+        }
+    }
+
+    fn get(&self) -> Vec<T> {
+        self.items.borrow().clone()
+    }
+
+    fn changes_count(&self) -> usize {
+        *self.changes_count.borrow()
+    }
+}
+
+fn content<T: 'static + Clone>(source: &dyn ObservableCollection<T>) -> Vec<T> {
+    source.into_iter().collect()
+}
+
+#[test]
+fn reconcile() {
+    testing::set_synchronous_mode(true);
+
+    let mut vec = ObservableVec::from_iter(vec![(1, "a"), (2, "b"), (3, "c"), (4, "d")]);
+    let replica = Replica::new(&vec);
+
+    vec.reconcile(vec![(4, "d"), (1, "a"), (5, "e"), (3, "C")], |(id, _)| *id);
+
+    assert_eq!(content(&vec), vec![(4, "d"), (1, "a"), (5, "e"), (3, "C")]);
+    assert_eq!(replica.get(), content(&vec));
+
+    // all the changes are notified with a single batch
+    assert_eq!(replica.changes_count(), 1);
+
+    testing::set_synchronous_mode(false);
+}
+
+///
+/// Runs every kind of change on the vec and calls `check` after each of them.
+///
+fn run_operations<F: FnMut(&ObservableVec<i32>)>(vec: &mut ObservableVec<i32>, mut check: F) {
+    vec.push(1);
+    check(vec);
+    vec.extend(vec![2, 3, 4, 5, 6, 7]);
+    check(vec);
+    vec.insert(3, 8);
+    check(vec);
+    vec.remove_at(1);
+    check(vec);
+    vec.set(2, 9);
+    check(vec);
+    vec.move_item(0, 4);
+    check(vec);
+    vec.move_item(5, 1);
+    check(vec);
+    vec.swap(2, 3);
+    check(vec);
+    vec.swap(6, 0);
+    check(vec);
+    vec.sort_by(|a, b| b.cmp(a));
+    check(vec);
+    vec.sort_by(|a, b| (a % 3).cmp(&(b % 3)));
+    check(vec);
+    vec.retain(|v| v % 2 == 1);
+    check(vec);
+    vec.reconcile(vec![7, 10, 1, 5], |v| *v);
+    check(vec);
+    vec.clear();
+    check(vec);
+}
+
+#[test]
+fn changes_of_vec() {
+    testing::set_synchronous_mode(true);
+
+    let mut vec = ObservableVec::new();
+    let replica = Replica::new(&vec);
+    let mut checks = 0;
+    run_operations(&mut vec, |vec| {
+        assert_eq!(replica.get(), content(vec));
+        checks += 1;
+    });
+    assert_eq!(replica.changes_count(), checks);
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn changes_of_mapped_vec() {
+    testing::set_synchronous_mode(true);
+
+    let mut vec = ObservableVec::new();
+    let mapped = vec.map(|v: &i32| v.to_string());
+    let replica = Replica::new(&mapped);
+    run_operations(&mut vec, |vec| {
+        let expected: Vec<String> = content(vec).iter().map(|v| v.to_string()).collect();
+        assert_eq!(content(&mapped), expected);
+        assert_eq!(replica.get(), expected);
+    });
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn changes_of_flat_mapped_vec() {
+    testing::set_synchronous_mode(true);
+
+    // items are expanded to a different number of items (also to none)
+    let mut vec = ObservableVec::new();
+    let flat_mapped = vec.flat_map(|v: &i32| vec![*v; (*v % 3) as usize]);
+    let replica = Replica::new(&flat_mapped);
+    run_operations(&mut vec, |vec| {
+        let expected: Vec<i32> = content(vec)
+            .iter()
+            .flat_map(|v| vec![*v; (*v % 3) as usize])
+            .collect();
+        assert_eq!(content(&flat_mapped), expected);
+        assert_eq!(replica.get(), expected);
+    });
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn changes_of_composite() {
+    testing::set_synchronous_mode(true);
+
+    let mut first = ObservableVec::from_iter(vec![100, 200]);
+    let mut second = ObservableVec::new();
+    let composite = ObservableComposite::from(vec![
+        Box::new(first.map(|v: &i32| *v)) as Box<dyn ObservableCollection<i32>>,
+        Box::new(second.map(|v: &i32| *v)),
+    ]);
+    let replica = Replica::new(&composite);
+
+    run_operations(&mut second, |second| {
+        let expected: Vec<i32> = content(&first).into_iter().chain(content(second)).collect();
+        assert_eq!(replica.get(), expected);
+    });
+
+    first.move_item(0, 1);
+    assert_eq!(replica.get(), vec![200, 100]);
+
+    testing::set_synchronous_mode(false);
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "serde")]
+
+use fui_core::serde::de::DeserializeSeed;
+use fui_core::*;
+use fui_macros::PersistentState;
+
+#[derive(PersistentState)]
+struct DocumentViewModel {
+    name: Property<String>,
+    tags: ObservableVec<String>,
+    #[state(skip)]
+    is_busy: Property<bool>,
+}
+
+impl DocumentViewModel {
+    fn new() -> Self {
+        DocumentViewModel {
+            name: Property::new("Untitled"),
+            tags: ObservableVec::new(),
+            is_busy: Property::new(false),
+        }
+    }
+}
+
+/// Runs the test in synchronous mode, so notifications are delivered immediately.
+fn run<F: FnOnce()>(f: F) {
+    testing::set_synchronous_mode(true);
+    f();
+    testing::set_synchronous_mode(false);
+}
+
+fn load(vm: &mut DocumentViewModel, json: &str) {
+    LoadedState(vm)
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+        .unwrap();
+}
+
+fn tags(vm: &DocumentViewModel) -> Vec<String> {
+    (0..vm.tags.len()).filter_map(|i| vm.tags.get(i)).collect()
+}
+
+#[test]
+fn load_then_mark_clean_stays_clean() {
+    run(|| {
+        let mut vm = DocumentViewModel::new();
+        vm.tags.push("old".to_string());
+        let tracker = ChangeTracker::new(&vm);
+
+        load(&mut vm, r#"{"name":"Report","tags":["a","b"]}"#);
+        tracker.mark_clean();
+
+        assert_eq!(vm.name.get(), "Report");
+        assert_eq!(tags(&vm), vec!["a", "b"]);
+        assert!(!tracker.is_dirty().get());
+    });
+}
+
+#[test]
+fn edit_marks_dirty() {
+    run(|| {
+        let mut vm = DocumentViewModel::new();
+        let tracker = ChangeTracker::new(&vm);
+        load(&mut vm, r#"{"name":"Report","tags":["a"]}"#);
+        tracker.mark_clean();
+
+        vm.tags.push("b".to_string());
+        assert!(tracker.is_dirty().get());
+
+        tracker.mark_clean();
+        assert!(!tracker.is_dirty().get());
+
+        vm.name.set("Summary".to_string());
+        assert!(tracker.is_dirty().get());
+
+        // back to the saved value
+        vm.name.set("Report".to_string());
+        tracker.mark_clean();
+        assert!(!tracker.is_dirty().get());
+    });
+}
+
+#[test]
+fn skipped_and_unknown_fields() {
+    run(|| {
+        let mut vm = DocumentViewModel::new();
+        vm.name.set("Report".to_string());
+        vm.tags.push("a".to_string());
+        vm.is_busy.set(true);
+
+        let json = serde_json::to_string(&SavedState(&vm)).unwrap();
+        assert_eq!(json, r#"{"name":"Report","tags":["a"]}"#);
+
+        let tracker = ChangeTracker::new(&vm);
+
+        // missing fields keep their values, unknown fields are ignored
+        load(&mut vm, r#"{"version":2,"name":"Summary","is_busy":false}"#);
+        tracker.mark_clean();
+        assert_eq!(vm.name.get(), "Summary");
+        assert_eq!(tags(&vm), vec!["a"]);
+        assert!(vm.is_busy.get());
+        assert!(!tracker.is_dirty().get());
+
+        // skipped fields are not tracked
+        vm.is_busy.set(false);
+        assert!(!tracker.is_dirty().get());
+    });
+}
@@ -0,0 +1,218 @@
+use drawing::primitive::Primitive;
+use fui_core::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use typemap::TypeMap;
+
+struct TestDrawingContext;
+
+impl DrawingContext for TestDrawingContext {
+    fn get_drawing_area_size(&self) -> (u16, u16) {
+        (0, 0)
+    }
+
+    fn get_resources(&mut self) -> &mut dyn Resources {
+        unimplemented!()
+    }
+
+    fn get_background_texture(&self) -> i32 {
+        0
+    }
+}
+
+///
+/// Control with the fixed size, growing by `growth` on every measure.
+///
+struct TestControl {
+    size: Size,
+    growth: f32,
+    measure_count: Rc<Cell<usize>>,
+}
+
+struct TestControlStyle;
+
+impl Style<TestControl> for TestControlStyle {
+    fn setup(&mut self, _data: &mut TestControl, _control_context: &mut ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut TestControl,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut TestControl,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _size: Size,
+    ) -> Size {
+        data.measure_count.set(data.measure_count.get() + 1);
+        let size = data.size;
+        data.size.width += data.growth;
+        size
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut TestControl,
+        _control_context: &mut ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+        _rect: Rect,
+    ) {
+    }
+
+    fn hit_test(
+        &self,
+        _data: &TestControl,
+        _control_context: &ControlContext,
+        _point: Point,
+    ) -> Option<Rc<RefCell<dyn ControlObject>>> {
+        None
+    }
+
+    fn to_primitives(
+        &self,
+        _data: &TestControl,
+        _control_context: &ControlContext,
+        _drawing_context: &mut dyn DrawingContext,
+    ) -> (Vec<Primitive>, Vec<Primitive>) {
+        (Vec::new(), Vec::new())
+    }
+}
+
+fn test_control(
+    width: f32,
+    growth: f32,
+    measure_count: &Rc<Cell<usize>>,
+) -> Rc<RefCell<dyn ControlObject>> {
+    StyledControl::new(
+        TestControl {
+            size: Size::new(width, 10.0f32),
+            growth,
+            measure_count: measure_count.clone(),
+        },
+        Box::new(TestControlStyle),
+        ViewContext::empty(),
+    )
+}
+
+fn view_context(children: Vec<Rc<RefCell<dyn ControlObject>>>) -> ViewContext {
+    ViewContext {
+        attached_values: TypeMap::new(),
+        children: children.into(),
+    }
+}
+
+///
+/// Grid with the label column (in the "Label" group) and the value column.
+///
+fn grid(label: Rc<RefCell<dyn ControlObject>>) -> Rc<RefCell<dyn ControlObject>> {
+    let measure_count = Rc::new(Cell::new(0));
+    Grid::builder()
+        .columns(2)
+        .widths(vec![(0, Length::Fill(1.0f32)), (1, Length::Auto)].into())
+        .shared_width_groups(vec![(0, "Label".to_string())])
+        .build()
+        .to_view(
+            None,
+            view_context(vec![label, test_control(10.0f32, 0.0f32, &measure_count)]),
+        )
+}
+
+fn measure(root: &Rc<RefCell<dyn ControlObject>>) {
+    let mut drawing_context = TestDrawingContext;
+    root.borrow_mut()
+        .measure(&mut drawing_context, Size::new(200.0f32, 200.0f32));
+    root.borrow_mut().set_rect(
+        &mut drawing_context,
+        Rect::new(0.0f32, 0.0f32, 200.0f32, 200.0f32),
+    );
+}
+
+fn label_width(grid: &Rc<RefCell<dyn ControlObject>>) -> f32 {
+    let grid = grid.borrow();
+    let label = grid.get_context().get_children().get(0).unwrap();
+    let width = label.borrow().get_rect().width;
+    width
+}
+
+fn value_x(grid: &Rc<RefCell<dyn ControlObject>>) -> f32 {
+    let grid = grid.borrow();
+    let value = grid.get_context().get_children().get(1).unwrap();
+    let x = value.borrow().get_rect().x;
+    x
+}
+
+#[test]
+fn grids_in_scope_have_the_same_column_width() {
+    testing::set_synchronous_mode(true);
+
+    let measure_count = Rc::new(Cell::new(0));
+    let grid1 = grid(test_control(30.0f32, 0.0f32, &measure_count));
+    let grid2 = grid(test_control(50.0f32, 0.0f32, &measure_count));
+    let scope = SharedSizeScope::builder().build().to_view(
+        None,
+        view_context(vec![Vertical::builder()
+            .build()
+            .to_view(None, view_context(vec![grid1.clone(), grid2.clone()]))]),
+    );
+
+    measure(&scope);
+
+    // the shared Fill column is sized to the largest content
+    assert_eq!(value_x(&grid1), 50.0f32);
+    assert_eq!(value_x(&grid2), 50.0f32);
+    assert_eq!(label_width(&grid2), 50.0f32);
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn grids_outside_scope_are_not_shared() {
+    testing::set_synchronous_mode(true);
+
+    let measure_count = Rc::new(Cell::new(0));
+    let grid1 = grid(test_control(30.0f32, 0.0f32, &measure_count));
+    let grid2 = grid(test_control(50.0f32, 0.0f32, &measure_count));
+    let root = Vertical::builder()
+        .build()
+        .to_view(None, view_context(vec![grid1.clone(), grid2.clone()]));
+
+    measure(&root);
+
+    assert_ne!(value_x(&grid1), value_x(&grid2));
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn measure_passes_are_limited() {
+    testing::set_synchronous_mode(true);
+
+    // converging groups are measured again only until the sizes are stable
+    let stable_count = Rc::new(Cell::new(0));
+    let scope = SharedSizeScope::builder().build().to_view(
+        None,
+        view_context(vec![grid(test_control(30.0f32, 0.0f32, &stable_count))]),
+    );
+    measure(&scope);
+    let measures_per_pass = stable_count.get() / 2;
+    assert!(measures_per_pass > 0);
+    assert_eq!(stable_count.get(), 2 * measures_per_pass);
+
+    // member growing on every measure never converges
+    let growing_count = Rc::new(Cell::new(0));
+    let scope = SharedSizeScope::builder().build().to_view(
+        None,
+        view_context(vec![grid(test_control(30.0f32, 1.0f32, &growing_count))]),
+    );
+    measure(&scope);
+    assert_eq!(growing_count.get(), 3 * measures_per_pass);
+
+    testing::set_synchronous_mode(false);
+}
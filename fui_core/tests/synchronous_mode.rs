@@ -0,0 +1,94 @@
+use fui_core::*;
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+#[test]
+fn binding_is_updated_right_after_set() {
+    testing::set_synchronous_mode(true);
+    assert!(testing::is_synchronous_mode());
+
+    let mut source = Property::new(1);
+    let target = Property::binded_from(&source);
+    let converted = Property::binded_c_from(&source, |v: i32| v.to_string());
+
+    source.set(2);
+    assert_eq!(target.get(), 2);
+    assert_eq!(converted.get(), "2");
+
+    testing::set_synchronous_mode(false);
+    assert!(!testing::is_synchronous_mode());
+}
+
+#[test]
+fn on_changed_is_called_right_after_set() {
+    testing::set_synchronous_mode(true);
+
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let mut property = Property::new(1);
+    let _subscription = property.on_changed({
+        let values = values.clone();
+        move |v| values.borrow_mut().push(v)
+    });
+
+    property.set(2);
+    property.set(3);
+    assert_eq!(*values.borrow(), vec![1, 2, 3]);
+
+    let changes = Rc::new(RefCell::new(0));
+    let mut vec = ObservableVec::new();
+    let _vec_subscription = vec.on_changed({
+        let changes = changes.clone();
+        move |_| *changes.borrow_mut() += 1
+    });
+    vec.push(1);
+    assert_eq!(*changes.borrow(), 1);
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn flush_runs_woken_tasks() {
+    testing::set_synchronous_mode(true);
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let _task = spawn_local({
+        let received = received.clone();
+        async move {
+            while let Some(value) = receiver.recv().await {
+                received.borrow_mut().push(value);
+            }
+        }
+    });
+
+    // sending only wakes the task
+    sender.send(1).unwrap();
+    sender.send(2).unwrap();
+    assert!(received.borrow().is_empty());
+
+    testing::flush();
+    assert_eq!(*received.borrow(), vec![1, 2]);
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn panicking_task_does_not_stop_the_executor() {
+    testing::set_synchronous_mode(true);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        spawn_local_and_forget(async {
+            panic!("task failed");
+        });
+    }));
+    assert!(result.is_err());
+
+    // tasks spawned later are still run immediately
+    let mut source = Property::new(1);
+    let target = Property::binded_from(&source);
+    source.set(2);
+    assert_eq!(target.get(), 2);
+
+    testing::set_synchronous_mode(false);
+}
@@ -0,0 +1,46 @@
+use fui_core::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn validators_run_once_per_change() {
+    testing::set_synchronous_mode(true);
+
+    let calls = Rc::new(Cell::new(0));
+    let mut name = Property::new("").with_validator({
+        let calls = calls.clone();
+        move |name: &String| {
+            calls.set(calls.get() + 1);
+            if name.is_empty() {
+                Err("Name is required".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    });
+    assert_eq!(name.errors().get(), vec!["Name is required"]);
+
+    let calls_before = calls.get();
+    name.set("Ann".to_string());
+    assert_eq!(calls.get(), calls_before + 1);
+    assert!(name.errors().get().is_empty());
+
+    testing::set_synchronous_mode(false);
+}
+
+#[test]
+fn existing_errors_does_not_create_validation() {
+    testing::set_synchronous_mode(true);
+
+    let text: Property<String> = Property::new("");
+    assert!(text.existing_errors().is_none());
+    assert!(text.is_valid());
+
+    let validated = Property::new("").with_validator(|_: &String| Err("Invalid".to_string()));
+    assert_eq!(
+        validated.existing_errors().map(|errors| errors.get()),
+        Some(vec!["Invalid".to_string()])
+    );
+
+    testing::set_synchronous_mode(false);
+}
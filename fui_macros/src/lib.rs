@@ -4,9 +4,10 @@ use proc_macro::TokenStream;
 
 use quote::quote;
 use syn::punctuated::Punctuated;
-use syn::{parse_macro_input, Ident, Token};
+use syn::{parse_macro_input, DeriveInput, Ident, Token};
 
 mod parser;
+mod persistent_state;
 use crate::parser::Ctrl;
 use crate::parser::CtrlParam;
 use crate::parser::CtrlProperty;
@@ -25,7 +26,8 @@ use crate::parser::CtrlProperty;
 //     }
 // )
 //
-// translates to:
+// translates to (every control is also wrapped in a block
+// entering `Diagnostics::control_scope::<Control>()`, omitted here):
 //
 // <Horizontal>::builder().spacing(4.into()).build().to_view(None, ViewContext {
 //     attached_values: { let mut map = TypeMap::new(); map.insert::<Row>(1.into()); map },
@@ -101,6 +103,20 @@ pub fn ui(input: TokenStream) -> TokenStream {
     //quote!(stringify!(#x)).into()
 }
 
+///
+/// Implements `fui_core::PersistentState` for the view model
+/// (requires `serde` feature of `fui_core`).
+///
+/// Fields marked with `#[state(skip)]` are not saved, loaded nor tracked.
+///
+#[proc_macro_derive(PersistentState, attributes(state))]
+pub fn derive_persistent_state(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    persistent_state::derive(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 fn quote_control(ctrl: Ctrl) -> proc_macro2::TokenStream {
     let Ctrl {
         name: control_name,
@@ -110,14 +126,18 @@ fn quote_control(ctrl: Ctrl) -> proc_macro2::TokenStream {
     let (style, properties, attached_values, children) = decouple_params(params);
 
     let properties_builder = get_properties_builder(control_name.clone(), properties);
-    let style_builder = get_style_builder(control_name, style);
+    let style_builder = get_style_builder(control_name.clone(), style);
     let attached_values_typemap = get_attached_values_typemap(attached_values);
     let children_source = get_children_source(children);
 
-    quote! { #properties_builder.to_view(#style_builder, ViewContext {
-        attached_values: #attached_values_typemap,
-        children: #children_source,
-    }) }
+    // resources created for the control are owned by it (see `Diagnostics`)
+    quote! { {
+        let _diagnostics_scope = Diagnostics::control_scope::<#control_name>();
+        #properties_builder.to_view(#style_builder, ViewContext {
+            attached_values: #attached_values_typemap,
+            children: #children_source,
+        })
+    } }
 }
 
 fn get_properties_builder(
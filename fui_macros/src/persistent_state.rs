@@ -0,0 +1,129 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Ident, Result};
+
+/// Implementation of `#[derive(PersistentState)]`.
+///
+/// ```ignore
+/// #[derive(PersistentState)]
+/// pub struct MainViewModel {
+///     pub name: Property<String>,
+///
+///     #[state(skip)]
+///     pub save_command: Command,
+/// }
+/// ```
+///
+/// Saves not skipped fields as a struct (field names are the keys)
+/// and loads them in place with `PersistentState::load_state()` of every field.
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let name_str = name.to_string();
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "PersistentState cannot be derived for generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "PersistentState can be derived only for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "PersistentState can be derived only for structs",
+            ))
+        }
+    };
+
+    let mut state_fields = Vec::new();
+    for field in fields {
+        if !is_skipped(&field.attrs)? {
+            state_fields.push(field.ident.clone().unwrap());
+        }
+    }
+    let keys: Vec<String> = state_fields.iter().map(|field| field.to_string()).collect();
+    let fields_count = state_fields.len();
+
+    Ok(quote! {
+        impl ::fui_core::PersistentState for #name {
+            fn save_state<S: ::fui_core::serde::Serializer>(&self, serializer: S)
+                -> ::std::result::Result<S::Ok, S::Error>
+            {
+                use ::fui_core::serde::ser::SerializeStruct;
+                let mut state = serializer.serialize_struct(#name_str, #fields_count)?;
+                #( state.serialize_field(#keys, &::fui_core::SavedState(&self.#state_fields))?; )*
+                state.end()
+            }
+
+            fn load_state<'de, D: ::fui_core::serde::Deserializer<'de>>(&mut self, deserializer: D)
+                -> ::std::result::Result<(), D::Error>
+            {
+                struct StateVisitor<'a>(&'a mut #name);
+
+                impl<'de, 'a> ::fui_core::serde::de::Visitor<'de> for StateVisitor<'a> {
+                    type Value = ();
+
+                    fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        formatter.write_str(concat!("state of ", #name_str))
+                    }
+
+                    fn visit_map<A: ::fui_core::serde::de::MapAccess<'de>>(self, mut map: A)
+                        -> ::std::result::Result<(), A::Error>
+                    {
+                        while let Some(key) = map.next_key::<::std::string::String>()? {
+                            match key.as_str() {
+                                #( #keys => map.next_value_seed(::fui_core::LoadedState(&mut self.0.#state_fields))?, )*
+                                _ => {
+                                    map.next_value::<::fui_core::serde::de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+
+                    fn visit_seq<A: ::fui_core::serde::de::SeqAccess<'de>>(self, mut seq: A)
+                        -> ::std::result::Result<(), A::Error>
+                    {
+                        #(
+                            if seq.next_element_seed(::fui_core::LoadedState(&mut self.0.#state_fields))?.is_none() {
+                                return Ok(());
+                            }
+                        )*
+                        Ok(())
+                    }
+                }
+
+                deserializer.deserialize_struct(#name_str, &[#(#keys),*], StateVisitor(self))
+            }
+
+            fn track_changes(&self, changes: &::fui_core::StateChanges) -> ::fui_core::Subscription {
+                ::fui_core::Subscription::Many(vec![
+                    #( ::fui_core::PersistentState::track_changes(&self.#state_fields, changes), )*
+                ])
+            }
+        }
+    })
+}
+
+fn is_skipped(attrs: &[syn::Attribute]) -> Result<bool> {
+    for attr in attrs {
+        if attr.path.is_ident("state") {
+            let arg: Ident = attr.parse_args()?;
+            if arg != "skip" {
+                return Err(Error::new_spanned(arg, "expected `skip`"));
+            }
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -148,6 +148,13 @@ pub struct ViewContext {
     children: Children,
 }
 
+/// Leak diagnostics (does nothing in the tests).
+pub struct Diagnostics;
+
+impl Diagnostics {
+    pub fn control_scope<D>() {}
+}
+
 pub trait Style<D> {
     fn draw(&self, data: &mut D) -> String;
 }